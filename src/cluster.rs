@@ -0,0 +1,82 @@
+// src/cluster.rs
+//! Peer-node registry backing distributed spawning and remote supervision.
+//!
+//! Turns Iris from a single-process actor runtime into a distributed one:
+//! peer nodes are registered here by a short `node_id` (as opposed to
+//! `network`'s raw socket addresses, which callers would otherwise have to
+//! keep re-typing), and every pid known to have been spawned on a peer is
+//! tracked so that `link`/`unlink`, `is_alive`, and `mailbox_size` know to
+//! forward across the network link instead of consulting the local
+//! supervisor/mailbox table. `watch` only records a pid's home node; the
+//! supervision it sets up stays local and is not forwarded. A `ChildSpec` factory that targets a
+//! peer resolves to a `(node_id, Pid)` pair rather than a bare `Pid`, so
+//! the supervisor can restart the child *on that same remote node* when
+//! it dies, mirroring the spawn-a-closure-on-another-process model where
+//! each spawned unit gets transparent bidirectional channels back to its
+//! parent.
+use dashmap::DashMap;
+
+/// Short, user-chosen identifier for a peer node, distinct from its
+/// address so a node can be re-dialed at a new address without every
+/// `ChildSpec`/link referencing it having to change.
+pub type NodeId = String;
+
+pub struct ClusterRegistry {
+    /// `node_id` -> dial address, the one the peer passed to
+    /// `PyRuntime.listen`.
+    peers: DashMap<NodeId, String>,
+    /// Which peer (if any) a given pid was spawned on; absent entries are
+    /// local. Consulted by `is_alive`/`mailbox_size`/link forwarding to
+    /// decide whether to go to the local supervisor/mailbox table or over
+    /// the network.
+    homes: DashMap<u64, NodeId>,
+}
+
+impl ClusterRegistry {
+    pub fn new() -> Self {
+        Self {
+            peers: DashMap::new(),
+            homes: DashMap::new(),
+        }
+    }
+
+    /// Register (or re-point) a peer node's dial address.
+    pub fn register_node(&self, node_id: NodeId, addr: String) {
+        self.peers.insert(node_id, addr);
+    }
+
+    /// Forget a peer node. Does not affect `homes` entries already
+    /// recorded against it; a dead peer's children simply fail their next
+    /// liveness/link check.
+    pub fn unregister_node(&self, node_id: &str) {
+        self.peers.remove(node_id);
+    }
+
+    /// Look up a registered peer's dial address.
+    pub fn resolve_node(&self, node_id: &str) -> Option<String> {
+        self.peers.get(node_id).map(|a| a.clone())
+    }
+
+    /// Record that `pid` lives on `node_id`, so liveness/link/mailbox
+    /// queries for it are forwarded there instead of checked locally.
+    pub fn set_home(&self, pid: u64, node_id: NodeId) {
+        self.homes.insert(pid, node_id);
+    }
+
+    /// Clear a pid's remote-home record (its owning node has confirmed the
+    /// actor exited, or the supervisor gave up restarting it).
+    pub fn clear_home(&self, pid: u64) {
+        self.homes.remove(&pid);
+    }
+
+    /// Which node (if any) `pid` lives on; `None` means local.
+    pub fn home_of(&self, pid: u64) -> Option<NodeId> {
+        self.homes.get(&pid).map(|n| n.clone())
+    }
+}
+
+impl Default for ClusterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,231 @@
+// src/dataspace.rs
+//! Reactive dataspace, inspired by Syndicate's actor model: actors publish
+//! facts (`assert_fact`/`retract_fact`) and others `subscribe` to patterns
+//! over them, receiving `SystemMessage::Assert`/`Retract` in their own
+//! mailbox whenever the set of matching facts changes. Grown on top of the
+//! same point-lookup idea as `NameRegistry`, but reactive rather than
+//! query-only, and tied into actor exit the same way `watch_path`
+//! subscriptions and monitors are: when an actor exits, `retract_all_for`
+//! withdraws every fact it owns, so dataspace state self-heals instead of
+//! leaking stale assertions.
+
+use crate::mailbox::{MailboxSender, SystemMessage};
+use crate::pid::Pid;
+use bytes::Bytes;
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Opaque identifier for one asserted fact, handed back by `assert_fact`
+/// and later passed to `retract_fact`. Also doubles as the subscription id
+/// returned by `subscribe`, since both are drawn from the same counter and
+/// never need to be told apart by the caller.
+pub type Handle = u64;
+
+struct Assertion {
+    owner: Pid,
+    value: Bytes,
+}
+
+struct Subscription {
+    /// Facts match a subscription when their value starts with `pattern`,
+    /// the same prefix convention `watch_path`/`list_children` use for
+    /// hierarchical paths.
+    pattern: Bytes,
+    mailbox: MailboxSender,
+}
+
+pub struct Dataspace {
+    assertions: DashMap<Handle, Assertion>,
+    /// Handles owned by a given actor, so `retract_all_for` can withdraw
+    /// all of them in one pass on exit without scanning every assertion.
+    owned: DashMap<Pid, Vec<Handle>>,
+    subscriptions: DashMap<Handle, Subscription>,
+    next_handle: AtomicU64,
+}
+
+impl Dataspace {
+    /// Create a new, empty dataspace.
+    pub fn new() -> Self {
+        Self {
+            assertions: DashMap::new(),
+            owned: DashMap::new(),
+            subscriptions: DashMap::new(),
+            next_handle: AtomicU64::new(1),
+        }
+    }
+
+    fn matches(pattern: &Bytes, value: &Bytes) -> bool {
+        value.len() >= pattern.len() && &value[..pattern.len()] == pattern.as_ref()
+    }
+
+    /// Publish `value` on behalf of `owner`, notifying every subscription
+    /// whose pattern matches. Returns a handle for `retract_fact`.
+    pub fn assert_fact(&self, owner: Pid, value: Bytes) -> Handle {
+        let handle = self.next_handle.fetch_add(1, Ordering::SeqCst);
+        self.assertions.insert(
+            handle,
+            Assertion {
+                owner,
+                value: value.clone(),
+            },
+        );
+        self.owned.entry(owner).or_default().push(handle);
+
+        for sub in self.subscriptions.iter() {
+            if Self::matches(&sub.pattern, &value) {
+                let _ = sub.mailbox.send_system(SystemMessage::Assert {
+                    handle,
+                    value: value.clone(),
+                });
+            }
+        }
+        handle
+    }
+
+    /// Withdraw a previously asserted fact, notifying every subscription
+    /// whose pattern matched it. A no-op if `handle` is unknown.
+    pub fn retract_fact(&self, handle: Handle) {
+        let Some((_, assertion)) = self.assertions.remove(&handle) else {
+            return;
+        };
+        if let Some(mut handles) = self.owned.get_mut(&assertion.owner) {
+            handles.retain(|h| *h != handle);
+        }
+        for sub in self.subscriptions.iter() {
+            if Self::matches(&sub.pattern, &assertion.value) {
+                let _ = sub.mailbox.send_system(SystemMessage::Retract { handle });
+            }
+        }
+    }
+
+    /// Subscribe `mailbox` to every current and future fact whose value
+    /// starts with `pattern`. Delivers every currently-standing match
+    /// first, then a `Synced` message, so the subscriber can tell it has
+    /// observed the full backlog before treating further `Assert`/
+    /// `Retract` messages as live updates.
+    pub fn subscribe(&self, pattern: Bytes, mailbox: MailboxSender) -> Handle {
+        for entry in self.assertions.iter() {
+            if Self::matches(&pattern, &entry.value.value) {
+                let _ = mailbox.send_system(SystemMessage::Assert {
+                    handle: *entry.key(),
+                    value: entry.value.value.clone(),
+                });
+            }
+        }
+        let _ = mailbox.send_system(SystemMessage::Synced);
+
+        let handle = self.next_handle.fetch_add(1, Ordering::SeqCst);
+        self.subscriptions
+            .insert(handle, Subscription { pattern, mailbox });
+        handle
+    }
+
+    /// Cancel a subscription returned by `subscribe`.
+    pub fn unsubscribe(&self, handle: Handle) {
+        self.subscriptions.remove(&handle);
+    }
+
+    /// Retract every fact `pid` owns. `pid`'s own subscriptions (if any)
+    /// are left for the caller to clear separately — a dead actor's
+    /// mailbox is useless as a subscription target, but tearing those down
+    /// is the linking/monitor machinery's job, same as it is for
+    /// `watch_path`. Call this from the same exit path that already
+    /// notifies monitors.
+    pub fn retract_all_for(&self, pid: Pid) {
+        if let Some((_, handles)) = self.owned.remove(&pid) {
+            for handle in handles {
+                self.retract_fact(handle);
+            }
+        }
+    }
+}
+
+impl Default for Dataspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mailbox::{self, Message};
+
+    #[tokio::test]
+    async fn subscribe_delivers_backlog_then_synced_then_live_updates() {
+        let ds = Dataspace::new();
+        let owner: Pid = 1;
+        let handle = ds.assert_fact(owner, Bytes::from_static(b"room/1/occupied"));
+
+        let (tx, mut rx) = mailbox::channel();
+        ds.subscribe(Bytes::from_static(b"room/1/"), tx);
+
+        match rx.recv().await.expect("backlog assert") {
+            Message::System(SystemMessage::Assert { handle: h, value }) => {
+                assert_eq!(h, handle);
+                assert_eq!(value.as_ref(), b"room/1/occupied");
+            }
+            other => panic!("expected Assert, got {other:?}"),
+        }
+        match rx.recv().await.expect("synced") {
+            Message::System(SystemMessage::Synced) => {}
+            other => panic!("expected Synced, got {other:?}"),
+        }
+
+        let live_handle = ds.assert_fact(owner, Bytes::from_static(b"room/1/vacant"));
+        match rx.recv().await.expect("live assert") {
+            Message::System(SystemMessage::Assert { handle: h, value }) => {
+                assert_eq!(h, live_handle);
+                assert_eq!(value.as_ref(), b"room/1/vacant");
+            }
+            other => panic!("expected Assert, got {other:?}"),
+        }
+
+        ds.retract_fact(live_handle);
+        match rx.recv().await.expect("retract") {
+            Message::System(SystemMessage::Retract { handle: h }) => assert_eq!(h, live_handle),
+            other => panic!("expected Retract, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn retract_all_for_withdraws_every_fact_owned_by_pid() {
+        let ds = Dataspace::new();
+        let owner: Pid = 7;
+        let other: Pid = 8;
+        let h1 = ds.assert_fact(owner, Bytes::from_static(b"a"));
+        let h2 = ds.assert_fact(owner, Bytes::from_static(b"b"));
+        let h3 = ds.assert_fact(other, Bytes::from_static(b"c"));
+
+        let (tx, mut rx) = mailbox::channel();
+        ds.subscribe(Bytes::from_static(b""), tx);
+        // Drain the three backlog Asserts plus Synced before exercising the
+        // exit-triggered retraction below.
+        for _ in 0..4 {
+            rx.recv().await.expect("backlog message");
+        }
+
+        ds.retract_all_for(owner);
+
+        let mut retracted = Vec::new();
+        for _ in 0..2 {
+            match rx.recv().await.expect("retract") {
+                Message::System(SystemMessage::Retract { handle }) => retracted.push(handle),
+                other => panic!("expected Retract, got {other:?}"),
+            }
+        }
+        retracted.sort_unstable();
+        assert_eq!(retracted, {
+            let mut expected = vec![h1, h2];
+            expected.sort_unstable();
+            expected
+        });
+
+        // `other`'s fact is untouched.
+        ds.retract_fact(h3);
+        match rx.recv().await.expect("other's retract still fires") {
+            Message::System(SystemMessage::Retract { handle }) => assert_eq!(handle, h3),
+            other => panic!("expected Retract, got {other:?}"),
+        }
+    }
+}
@@ -2,21 +2,191 @@
 //! Minimal mailbox implementation (unbounded, binary messages)
 
 use bytes::Bytes;
+use futures::Stream;
 use std::collections::VecDeque;
-use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
-use tokio::sync::mpsc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, atomic::{AtomicBool, AtomicUsize, Ordering}};
+use std::task::{Context, Poll};
+use tokio::sync::{mpsc, Notify};
 
-/// Underlying sender type for user messages; either unbounded or bounded.
-#[derive(Clone)]
+/// A user payload together with its correlation tag. Tag `0` means
+/// "untagged" and never matches a `recv_tagged` filter.
+#[derive(Clone, Debug)]
+struct TaggedPayload {
+    tag: u64,
+    data: Bytes,
+}
+
+/// Overflow policy for a bounded mailbox's user-message queue, selected at
+/// construction via `bounded_channel_with_policy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MailboxPolicy {
+    /// Reject the new message once the queue is full (`bounded_channel`'s
+    /// existing default).
+    DropNew,
+    /// Evict the oldest queued message to make room for the new one. Never
+    /// rejects a send; useful for lossy telemetry where the newest sample
+    /// matters more than a complete history.
+    DropOldest,
+    /// Suspend the sender (via `send_async`/`reserve`) until a slot frees
+    /// up. The non-blocking `send` has no way to wait, so under `Block` it
+    /// still rejects a full queue rather than deadlocking the caller.
+    Block,
+}
+
+/// Shared backing store for a bounded mailbox's user-message queue.
+/// Replaces `tokio::sync::mpsc` for this path because `DropOldest` needs to
+/// reach into the queue and evict its front element, which an `mpsc`
+/// channel's opaque internals don't allow.
+struct BoundedQueue {
+    capacity: usize,
+    policy: MailboxPolicy,
+    inner: Mutex<VecDeque<TaggedPayload>>,
+    /// Notified whenever a message is pushed; wakes a waiting receiver.
+    not_empty: Notify,
+    /// Notified whenever a message is popped (or the receiver is dropped);
+    /// wakes a sender blocked under `MailboxPolicy::Block`.
+    not_full: Notify,
+    receiver_dropped: AtomicBool,
+    sender_count: AtomicUsize,
+}
+
+impl BoundedQueue {
+    fn new(capacity: usize, policy: MailboxPolicy) -> Self {
+        BoundedQueue {
+            capacity,
+            policy,
+            inner: Mutex::new(VecDeque::with_capacity(capacity)),
+            not_empty: Notify::new(),
+            not_full: Notify::new(),
+            receiver_dropped: AtomicBool::new(false),
+            sender_count: AtomicUsize::new(1),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.receiver_dropped.load(Ordering::SeqCst) || self.sender_count.load(Ordering::SeqCst) == 0
+    }
+
+    fn has_capacity(&self) -> bool {
+        self.inner.lock().unwrap().len() < self.capacity
+    }
+
+    /// Attempt to enqueue `payload` without waiting. Under `Block`, a full
+    /// queue is rejected here the same as `DropNew` would be; true blocking
+    /// is layered on top by callers that loop on `not_full`.
+    ///
+    /// Returns `Ok(Some(evicted))` when `DropOldest` made room by evicting
+    /// the previous front of the queue, so the caller can keep its queued
+    /// counter accurate (one message left, one arrived: no net change).
+    fn try_enqueue(&self, payload: TaggedPayload) -> Result<Option<TaggedPayload>, TaggedPayload> {
+        let mut guard = self.inner.lock().unwrap();
+        if guard.len() < self.capacity {
+            guard.push_back(payload);
+            drop(guard);
+            self.not_empty.notify_waiters();
+            return Ok(None);
+        }
+        match self.policy {
+            MailboxPolicy::DropOldest => {
+                let evicted = guard.pop_front();
+                guard.push_back(payload);
+                drop(guard);
+                self.not_empty.notify_waiters();
+                Ok(evicted)
+            }
+            MailboxPolicy::DropNew | MailboxPolicy::Block => Err(payload),
+        }
+    }
+
+    fn try_pop(&self) -> Option<TaggedPayload> {
+        let mut guard = self.inner.lock().unwrap();
+        let popped = guard.pop_front();
+        drop(guard);
+        if popped.is_some() {
+            self.not_full.notify_waiters();
+        }
+        popped
+    }
+}
+
+/// Poll the shared queue for a message without blocking, registering `cx`'s
+/// waker against `not_empty` before giving up. Used by both `poll_next`
+/// (the `Stream` impl) and any future poll-based entry point.
+fn poll_bounded_recv(q: &Arc<BoundedQueue>, cx: &mut Context<'_>) -> Poll<Option<TaggedPayload>> {
+    if let Some(p) = q.try_pop() {
+        return Poll::Ready(Some(p));
+    }
+    if q.sender_count.load(Ordering::SeqCst) == 0 {
+        return Poll::Ready(None);
+    }
+
+    let notified = q.not_empty.notified();
+    tokio::pin!(notified);
+    notified.as_mut().enable();
+
+    // A push could have landed between the `try_pop` above and `enable()`
+    // registering our waker; check once more before returning `Pending`.
+    if let Some(p) = q.try_pop() {
+        return Poll::Ready(Some(p));
+    }
+    match notified.as_mut().poll(cx) {
+        Poll::Ready(()) => match q.try_pop() {
+            Some(p) => Poll::Ready(Some(p)),
+            None => Poll::Pending,
+        },
+        Poll::Pending => Poll::Pending,
+    }
+}
+
+/// Underlying sender type for user messages; either unbounded, or bounded
+/// with a selectable `MailboxPolicy` (plain `bounded_channel` is the
+/// `DropNew` case).
 enum UserSender {
-    Unbounded(mpsc::UnboundedSender<Bytes>),
-    Bounded(mpsc::Sender<Bytes>),
+    Unbounded(mpsc::UnboundedSender<TaggedPayload>),
+    Bounded(Arc<BoundedQueue>),
+}
+
+impl Clone for UserSender {
+    fn clone(&self) -> Self {
+        match self {
+            UserSender::Unbounded(tx) => UserSender::Unbounded(tx.clone()),
+            UserSender::Bounded(q) => {
+                q.sender_count.fetch_add(1, Ordering::SeqCst);
+                UserSender::Bounded(q.clone())
+            }
+        }
+    }
+}
+
+impl Drop for UserSender {
+    fn drop(&mut self) {
+        if let UserSender::Bounded(q) = self {
+            if q.sender_count.fetch_sub(1, Ordering::SeqCst) == 1 {
+                // Last sender gone; wake a receiver blocked in `recv_raw_user`
+                // so it can observe the queue is now permanently empty.
+                q.not_empty.notify_waiters();
+            }
+        }
+    }
 }
 
 /// Underlying receiver type for user messages.
 enum UserReceiver {
-    Unbounded(mpsc::UnboundedReceiver<Bytes>),
-    Bounded(mpsc::Receiver<Bytes>),
+    Unbounded(mpsc::UnboundedReceiver<TaggedPayload>),
+    Bounded(Arc<BoundedQueue>),
+}
+
+impl Drop for UserReceiver {
+    fn drop(&mut self) {
+        if let UserReceiver::Bounded(q) = self {
+            q.receiver_dropped.store(true, Ordering::SeqCst);
+            // Wake any sender blocked under `MailboxPolicy::Block` so it can
+            // observe the mailbox is closed instead of waiting forever.
+            q.not_full.notify_waiters();
+        }
+    }
 }
 
 /// Message is an envelope that can be either a user payload (binary blob)
@@ -48,6 +218,32 @@ pub enum SystemMessage {
     Ping,
     /// Response to a heartbeat signal.
     Pong,
+    /// Request/response invocation: run the actor's behavior against the
+    /// payload and deliver its return value through the boxed oneshot
+    /// sender recovered from `reply_ptr`, the same raw-pointer smuggling
+    /// `HotSwap` uses to cross this `Clone`/`Eq`-derived enum.
+    Call(Bytes, usize),
+    /// Dataspace: a fact matching one of this actor's `subscribe_dataspace`
+    /// patterns was asserted (see `crate::dataspace`).
+    Assert { handle: u64, value: Bytes },
+    /// Dataspace: a previously-asserted, matching fact was withdrawn,
+    /// either explicitly via `retract_fact` or because its owner exited.
+    Retract { handle: u64 },
+    /// Dataspace: sent once to a new subscriber after every
+    /// currently-standing matching assertion has been delivered, so it can
+    /// tell it has caught up on the backlog rather than racing it against
+    /// live updates.
+    Synced,
+    /// Delivered to a monitoring actor's mailbox when the target of a
+    /// `monitor`/`monitor_remote` call exits. `handle` identifies which
+    /// `monitor` call this corresponds to (a monitor can watch several
+    /// targets, or several monitors can watch the same target); `reason`
+    /// is the same `ExitInfo` `Exit` carries.
+    Down {
+        handle: u64,
+        pid: u64,
+        reason: ExitInfo,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -67,12 +263,18 @@ pub struct MailboxSender {
     counter: Arc<AtomicUsize>,
 }
 
-/// Receiver half of a mailbox.
+/// Receiver half of a mailbox. Stashed messages keep their correlation tag
+/// (`0` for untagged/system messages) alongside the envelope so `recv_tagged`
+/// can match against deferred entries without losing tag information.
 pub struct MailboxReceiver {
     rx_user: UserReceiver,
     rx_sys: mpsc::UnboundedReceiver<SystemMessage>,
-    stash: VecDeque<Message>,
+    stash: VecDeque<(u64, Message)>,
     counter: Arc<AtomicUsize>,
+    /// Whether EXIT signals are delivered into the mailbox as ordinary
+    /// messages (`true`, the default) or are left for the caller to treat as
+    /// fatal (`false`). Mirrors lunatic's "linked and trapping" actor flag.
+    trap_exit: Arc<AtomicBool>,
 }
 
 /// Create a new mailbox channel (sender, receiver).
@@ -88,6 +290,7 @@ pub fn channel() -> (MailboxSender, MailboxReceiver) {
             rx_sys,
             stash: VecDeque::new(),
             counter: counter.clone(),
+            trap_exit: Arc::new(AtomicBool::new(true)),
         },
     )
 }
@@ -95,16 +298,27 @@ pub fn channel() -> (MailboxSender, MailboxReceiver) {
 /// Create a bounded mailbox channel with given capacity. If the queue is
 /// full, `send` will return Err(msg) (drop-new policy).
 pub fn bounded_channel(capacity: usize) -> (MailboxSender, MailboxReceiver) {
-    let (tx_user, rx_user) = mpsc::channel(capacity);
+    bounded_channel_with_policy(capacity, MailboxPolicy::DropNew)
+}
+
+/// Create a bounded mailbox channel with an explicit overflow `policy`:
+/// `DropNew` (today's default), `DropOldest` (lossy telemetry — always
+/// accepts the newest message, evicting the oldest queued one to make
+/// room), or `Block` (`send_async`/`reserve` suspend until a slot frees;
+/// the non-blocking `send` still rejects a full queue, as it has no way to
+/// wait).
+pub fn bounded_channel_with_policy(capacity: usize, policy: MailboxPolicy) -> (MailboxSender, MailboxReceiver) {
+    let queue = Arc::new(BoundedQueue::new(capacity, policy));
     let (tx_sys, rx_sys) = mpsc::unbounded_channel();
     let counter = Arc::new(AtomicUsize::new(0));
     (
-        MailboxSender { tx_user: UserSender::Bounded(tx_user), tx_sys, counter: counter.clone() },
+        MailboxSender { tx_user: UserSender::Bounded(queue.clone()), tx_sys, counter: counter.clone() },
         MailboxReceiver {
-            rx_user: UserReceiver::Bounded(rx_user),
+            rx_user: UserReceiver::Bounded(queue),
             rx_sys,
             stash: VecDeque::new(),
             counter: counter.clone(),
+            trap_exit: Arc::new(AtomicBool::new(true)),
         },
     )
 }
@@ -114,23 +328,9 @@ impl MailboxSender {
     /// For bounded user queues, policy is drop-new: error returned when full.
     pub fn send(&self, msg: Message) -> Result<(), Message> {
         match msg {
-            Message::User(b) => {
-                // increment counter before enqueue attempt
-                self.counter.fetch_add(1, Ordering::SeqCst);
-                let backup = Message::User(b.clone());
-                let res = match &self.tx_user {
-                    UserSender::Unbounded(tx) => tx.send(b).map_err(|_| backup.clone()),
-                    UserSender::Bounded(tx) => match tx.try_send(b) {
-                        Ok(()) => Ok(()),
-                        Err(_e) => Err(backup.clone()),
-                    },
-                };
-                if res.is_err() {
-                    // rollback counter
-                    self.counter.fetch_sub(1, Ordering::SeqCst);
-                }
-                res
-            }
+            Message::User(b) => self
+                .enqueue_user(0, b)
+                .map_err(Message::User),
             Message::System(s) => {
                 let backup = Message::System(s.clone());
                 match self.tx_sys.send(s) {
@@ -141,24 +341,55 @@ impl MailboxSender {
         }
     }
 
-    /// Convenience: send user bytes directly.
-    pub fn send_user_bytes(&self, b: Bytes) -> Result<(), Bytes> {
-        self.counter.fetch_add(1, Ordering::SeqCst);
-        let backup = b.clone();
-        let res = match &self.tx_user {
-            UserSender::Unbounded(tx) => tx.send(b).map_err(|_e| backup.clone()),
-            UserSender::Bounded(tx) => match tx.try_send(b) {
-                Ok(()) => Ok(()),
-                Err(err) => match err {
-                    mpsc::error::TrySendError::Full(_) => Err(backup.clone()),
-                    mpsc::error::TrySendError::Closed(_) => Err(backup.clone()),
-                },
-            },
-        };
-        if res.is_err() {
-            self.counter.fetch_sub(1, Ordering::SeqCst);
+    /// Send a user payload stamped with a correlation `tag`. Tag `0` is
+    /// reserved for "untagged" and will never match a `recv_tagged` filter;
+    /// callers that want request/reply semantics should pick a nonzero tag
+    /// (e.g. a per-request counter) and have the replier echo it back.
+    pub fn send_tagged(&self, tag: u64, b: Bytes) -> Result<(), Bytes> {
+        self.enqueue_user(tag, b)
+    }
+
+    /// Convenience: reply to a tagged request by stamping the response with
+    /// the same tag the request carried.
+    pub fn reply(&self, tag: u64, b: Bytes) -> Result<(), Bytes> {
+        self.send_tagged(tag, b)
+    }
+
+    fn enqueue_user(&self, tag: u64, b: Bytes) -> Result<(), Bytes> {
+        let payload = TaggedPayload { tag, data: b };
+        match &self.tx_user {
+            UserSender::Unbounded(tx) => {
+                let backup = payload.data.clone();
+                match tx.send(payload) {
+                    Ok(()) => {
+                        self.counter.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                    Err(_) => Err(backup),
+                }
+            }
+            UserSender::Bounded(q) => {
+                if q.is_closed() {
+                    return Err(payload.data);
+                }
+                let backup = payload.data.clone();
+                match q.try_enqueue(payload) {
+                    Ok(None) => {
+                        self.counter.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                    // DropOldest: one message left the queue, one arrived —
+                    // the queued count doesn't change.
+                    Ok(Some(_evicted)) => Ok(()),
+                    Err(_rejected) => Err(backup),
+                }
+            }
         }
-        res
+    }
+
+    /// Convenience: send user bytes directly (untagged).
+    pub fn send_user_bytes(&self, b: Bytes) -> Result<(), Bytes> {
+        self.enqueue_user(0, b)
     }
 
     /// Convenience: send system message directly.
@@ -174,9 +405,204 @@ impl MailboxSender {
     pub fn len(&self) -> usize {
         self.counter.load(Ordering::SeqCst)
     }
+
+    /// Send a message, suspending on a full bounded mailbox until a slot
+    /// frees up instead of dropping the message. Unbounded mailboxes (and
+    /// system messages, whose channel is always unbounded) complete
+    /// immediately, same as `send`. Resolves to `Err` only once the
+    /// receiver is gone.
+    pub async fn send_async(&self, msg: Message) -> Result<(), Message> {
+        match msg {
+            Message::User(b) => self.enqueue_user_async(0, b).await.map_err(Message::User),
+            Message::System(s) => self.send(Message::System(s)),
+        }
+    }
+
+    /// Convenience: send user bytes directly (untagged), suspending on a
+    /// full bounded mailbox. See `send_async`.
+    pub async fn send_user_bytes_async(&self, b: Bytes) -> Result<(), Bytes> {
+        self.enqueue_user_async(0, b).await
+    }
+
+    /// Convenience: send a tagged user payload, suspending on a full
+    /// bounded mailbox. See `send_async`.
+    pub async fn send_tagged_async(&self, tag: u64, b: Bytes) -> Result<(), Bytes> {
+        self.enqueue_user_async(tag, b).await
+    }
+
+    async fn enqueue_user_async(&self, tag: u64, b: Bytes) -> Result<(), Bytes> {
+        let payload = TaggedPayload { tag, data: b };
+        match &self.tx_user {
+            UserSender::Unbounded(tx) => {
+                let backup = payload.data.clone();
+                match tx.send(payload) {
+                    Ok(()) => {
+                        self.counter.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                    Err(_) => Err(backup),
+                }
+            }
+            UserSender::Bounded(q) => {
+                let mut payload = payload;
+                loop {
+                    if q.is_closed() {
+                        return Err(payload.data);
+                    }
+                    match q.try_enqueue(payload) {
+                        Ok(None) => {
+                            self.counter.fetch_add(1, Ordering::SeqCst);
+                            return Ok(());
+                        }
+                        Ok(Some(_evicted)) => return Ok(()),
+                        Err(rejected) => {
+                            if q.policy != MailboxPolicy::Block {
+                                return Err(rejected.data);
+                            }
+                            // Register interest before re-checking, so a
+                            // `not_full` notification fired between our
+                            // rejected attempt above and this `.await` is
+                            // never missed.
+                            let notified = q.not_full.notified();
+                            tokio::pin!(notified);
+                            notified.as_mut().enable();
+                            payload = rejected;
+                            if !q.has_capacity() && !q.is_closed() {
+                                notified.await;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reserve a slot in the mailbox without yet constructing the payload,
+    /// mirroring `tokio::sync::mpsc`'s reserve/permit split: a producer can
+    /// check admission — and suspend on a full bounded mailbox — before
+    /// paying for an expensive `Bytes` it might otherwise have to drop.
+    /// Resolves to `Err(Closed)` only once the receiver is gone.
+    ///
+    /// The returned `Permit` does not affect `len()` until `Permit::send`
+    /// commits it: counting a reservation before the caller has actually
+    /// handed over a payload would make `len()` overcount work that may
+    /// never materialize (the permit can still be dropped uncommitted).
+    ///
+    /// Unlike `tokio::sync::mpsc`'s permit, a reservation against a
+    /// `MailboxPolicy`-governed bounded queue isn't a guaranteed slot:
+    /// between `reserve` returning and `Permit::send` committing, a
+    /// concurrent sender can still fill the gap. `Permit::send` then just
+    /// re-runs the same admission decision the non-blocking `send` would,
+    /// honoring `policy` rather than assuming the slot is still free: it
+    /// never waits, so under `DropNew` or `Block` a full queue drops the
+    /// payload.
+    pub async fn reserve(&self) -> Result<Permit, Closed> {
+        match &self.tx_user {
+            UserSender::Unbounded(tx) => Ok(Permit {
+                inner: PermitInner::Unbounded(tx.clone()),
+                counter: self.counter.clone(),
+            }),
+            UserSender::Bounded(q) => {
+                loop {
+                    if q.is_closed() {
+                        return Err(Closed);
+                    }
+                    // Register interest before checking capacity, so a
+                    // `not_full` notification fired between the check and
+                    // the `.await` below is never missed.
+                    let notified = q.not_full.notified();
+                    tokio::pin!(notified);
+                    notified.as_mut().enable();
+                    if q.has_capacity() {
+                        return Ok(Permit {
+                            inner: PermitInner::Bounded(q.clone()),
+                            counter: self.counter.clone(),
+                        });
+                    }
+                    notified.await;
+                }
+            }
+        }
+    }
+}
+
+/// Error returned by `MailboxSender::reserve` once the mailbox's receiver
+/// has been dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed;
+
+enum PermitInner {
+    Unbounded(mpsc::UnboundedSender<TaggedPayload>),
+    Bounded(Arc<BoundedQueue>),
+}
+
+/// A reservation obtained from `MailboxSender::reserve`. Dropping it without
+/// calling `send` never increments the queued-message counter.
+pub struct Permit {
+    inner: PermitInner,
+    counter: Arc<AtomicUsize>,
+}
+
+impl Permit {
+    /// Commit the reservation, stamping the payload with `tag` (`0` for
+    /// untagged). Increments the queued-message counter only now, on
+    /// commit, not back when the permit was reserved.
+    pub fn send(self, tag: u64, b: Bytes) {
+        let payload = TaggedPayload { tag, data: b };
+        match self.inner {
+            PermitInner::Unbounded(tx) => {
+                let _ = tx.send(payload);
+                self.counter.fetch_add(1, Ordering::SeqCst);
+            }
+            PermitInner::Bounded(q) => match q.try_enqueue(payload) {
+                Ok(None) => {
+                    self.counter.fetch_add(1, Ordering::SeqCst);
+                }
+                Ok(Some(_evicted)) => {}
+                // Capacity vanished between `reserve` and `send`; drop
+                // silently, same as a `DropNew`/`Block` `send` would.
+                Err(_rejected) => {}
+            },
+        }
+    }
 }
 
 impl MailboxReceiver {
+    /// Set whether EXIT signals should be trapped (delivered into the
+    /// mailbox as an ordinary `SystemMessage::Exit`) or left untrapped, in
+    /// which case callers are expected to treat a non-`Normal` EXIT as
+    /// fatal rather than as a regular message.
+    pub fn set_trap_exit(&self, trap: bool) {
+        self.trap_exit.store(trap, Ordering::SeqCst);
+    }
+
+    /// Whether this mailbox currently traps EXIT signals.
+    pub fn is_trapping_exit(&self) -> bool {
+        self.trap_exit.load(Ordering::SeqCst)
+    }
+
+    /// Pop the next raw payload off the underlying user channel, tag included.
+    async fn recv_raw_user(&mut self) -> Option<TaggedPayload> {
+        match &mut self.rx_user {
+            UserReceiver::Unbounded(rx) => rx.recv().await,
+            UserReceiver::Bounded(q) => loop {
+                if let Some(p) = q.try_pop() {
+                    return Some(p);
+                }
+                if q.sender_count.load(Ordering::SeqCst) == 0 {
+                    return None;
+                }
+                let notified = q.not_empty.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+                if let Some(p) = q.try_pop() {
+                    return Some(p);
+                }
+                notified.await;
+            },
+        }
+    }
+
     /// Await a message from the mailbox, prioritizing any already-enqueued
     /// system messages.
     pub async fn recv(&mut self) -> Option<Message> {
@@ -184,9 +610,9 @@ impl MailboxReceiver {
         if let Some(pos) = self
             .stash
             .iter()
-            .position(|m| matches!(m, Message::System(_)))
+            .position(|(_, m)| matches!(m, Message::System(_)))
         {
-            return self.stash.remove(pos);
+            return self.stash.remove(pos).map(|(_, m)| m);
         }
 
         if let Ok(sys) = self.rx_sys.try_recv() {
@@ -194,7 +620,7 @@ impl MailboxReceiver {
         }
 
         // If there are deferred user messages, deliver them before awaiting new ones.
-        if let Some(front) = self.stash.pop_front() {
+        if let Some((_, front)) = self.stash.pop_front() {
             if matches!(front, Message::User(_)) {
                 self.counter.fetch_sub(1, Ordering::SeqCst);
             }
@@ -209,17 +635,10 @@ impl MailboxReceiver {
                     None => None,
                 }
             }
-            user = {
-                async {
-                    match &mut self.rx_user {
-                        UserReceiver::Unbounded(rx) => rx.recv().await.map(Message::User),
-                        UserReceiver::Bounded(rx) => rx.recv().await.map(Message::User),
-                    }
-                }
-            } => {
-                if let Some(m) = user {
+            user = self.recv_raw_user() => {
+                if let Some(p) = user {
                     self.counter.fetch_sub(1, Ordering::SeqCst);
-                    Some(m)
+                    Some(Message::User(p.data))
                 } else {
                     None
                 }
@@ -233,9 +652,9 @@ impl MailboxReceiver {
         if let Some(pos) = self
             .stash
             .iter()
-            .position(|m| matches!(m, Message::System(_)))
+            .position(|(_, m)| matches!(m, Message::System(_)))
         {
-            return self.stash.remove(pos);
+            return self.stash.remove(pos).map(|(_, m)| m);
         }
 
         if let Ok(sys) = self.rx_sys.try_recv() {
@@ -243,7 +662,7 @@ impl MailboxReceiver {
         }
 
         // Deliver deferred user messages first, then try underlying channel.
-        if let Some(front) = self.stash.pop_front() {
+        if let Some((_, front)) = self.stash.pop_front() {
             if matches!(front, Message::User(_)) {
                 self.counter.fetch_sub(1, Ordering::SeqCst);
             }
@@ -252,11 +671,11 @@ impl MailboxReceiver {
 
         let opt = match &mut self.rx_user {
             UserReceiver::Unbounded(rx) => rx.try_recv().ok(),
-            UserReceiver::Bounded(rx) => rx.try_recv().ok(),
+            UserReceiver::Bounded(q) => q.try_pop(),
         };
-        opt.map(|b| {
+        opt.map(|p| {
             self.counter.fetch_sub(1, Ordering::SeqCst);
-            Message::User(b)
+            Message::User(p.data)
         })
     }
 
@@ -268,12 +687,12 @@ impl MailboxReceiver {
         F: FnMut(&Message) -> bool,
     {
         // First, search stash for a matching message (preserve ordering).
-        if let Some(idx) = self.stash.iter().position(|m| matcher(m)) {
-            let m = self.stash.remove(idx);
-            if let Some(Message::User(_)) = m.as_ref() {
+        if let Some(idx) = self.stash.iter().position(|(_, m)| matcher(m)) {
+            let (_, m) = self.stash.remove(idx)?;
+            if let Message::User(_) = &m {
                 self.counter.fetch_sub(1, Ordering::SeqCst);
             }
-            return m;
+            return Some(m);
         }
 
         loop {
@@ -283,7 +702,7 @@ impl MailboxReceiver {
                 if matcher(&m) {
                     return Some(m);
                 } else {
-                    self.stash.push_back(m);
+                    self.stash.push_back((0, m));
                     continue;
                 }
             }
@@ -297,28 +716,22 @@ impl MailboxReceiver {
                             if matcher(&m) {
                                 return Some(m);
                             } else {
-                                self.stash.push_back(m);
+                                self.stash.push_back((0, m));
                                 continue;
                             }
                         }
                         None => return None,
                     }
                 }
-                user = {
-                    async {
-                        match &mut self.rx_user {
-                            UserReceiver::Unbounded(rx) => rx.recv().await.map(Message::User),
-                            UserReceiver::Bounded(rx) => rx.recv().await.map(Message::User),
-                        }
-                    }
-                } => {
+                user = self.recv_raw_user() => {
                     match user {
-                        Some(m) => {
+                        Some(p) => {
+                            let m = Message::User(p.data);
                             if matcher(&m) {
                                 self.counter.fetch_sub(1, Ordering::SeqCst);
                                 return Some(m);
                             } else {
-                                self.stash.push_back(m);
+                                self.stash.push_back((p.tag, m));
                                 continue;
                             }
                         }
@@ -328,6 +741,115 @@ impl MailboxReceiver {
             }
         }
     }
+
+    /// Selective receive by correlation tag: await the first `User` message
+    /// whose tag is contained in `tags`, leaving every other message (tagged
+    /// or not) in place in mailbox order. Matching happens entirely over the
+    /// stored tag, so unlike `selective_recv` it never re-enters a predicate
+    /// callback per buffered message. Tag `0` (untagged) never matches.
+    pub async fn recv_tagged(&mut self, tags: &[u64]) -> Option<Message> {
+        debug_assert!(
+            !tags.contains(&0),
+            "tag 0 is reserved for untagged messages and never matches"
+        );
+
+        if let Some(idx) = self
+            .stash
+            .iter()
+            .position(|(tag, m)| *tag != 0 && tags.contains(tag) && matches!(m, Message::User(_)))
+        {
+            let (_, m) = self.stash.remove(idx)?;
+            self.counter.fetch_sub(1, Ordering::SeqCst);
+            return Some(m);
+        }
+
+        loop {
+            if let Ok(sys) = self.rx_sys.try_recv() {
+                self.stash.push_back((0, Message::System(sys)));
+                continue;
+            }
+
+            tokio::select! {
+                biased;
+                sys = self.rx_sys.recv() => {
+                    match sys {
+                        Some(s) => {
+                            self.stash.push_back((0, Message::System(s)));
+                            continue;
+                        }
+                        None => return None,
+                    }
+                }
+                user = self.recv_raw_user() => {
+                    match user {
+                        Some(p) => {
+                            if p.tag != 0 && tags.contains(&p.tag) {
+                                self.counter.fetch_sub(1, Ordering::SeqCst);
+                                return Some(Message::User(p.data));
+                            } else {
+                                self.stash.push_back((p.tag, Message::User(p.data)));
+                                continue;
+                            }
+                        }
+                        None => return None,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Yields the mailbox as an async `Stream<Item = Message>`, so consumers can
+/// reach for `StreamExt` combinators (`ready_chunks`/`chunks` for batching,
+/// `throttle`/`timeout` for rate limiting, `take_until` for graceful
+/// shutdown) instead of hand-writing `while let Some(m) = rx.recv().await`.
+///
+/// `poll_next` reimplements `recv`'s ordering rather than calling it: system
+/// messages (stashed, then the `rx_sys` channel) are always drained before
+/// user payloads, and `counter` is decremented only for a delivered
+/// `Message::User`. All fields are `Unpin`, so polling never needs the
+/// pinning machinery beyond `Pin::get_mut`.
+impl Stream for MailboxReceiver {
+    type Item = Message;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Message>> {
+        let this = self.get_mut();
+
+        // Prefer any system messages already in the stash.
+        if let Some(pos) = this.stash.iter().position(|(_, m)| matches!(m, Message::System(_))) {
+            let (_, m) = this.stash.remove(pos).expect("position() found an index");
+            return Poll::Ready(Some(m));
+        }
+
+        // Biased: poll the system channel before the user channel, same as
+        // `recv`'s `tokio::select! { biased; ... }`.
+        match this.rx_sys.poll_recv(cx) {
+            Poll::Ready(Some(s)) => return Poll::Ready(Some(Message::System(s))),
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => {}
+        }
+
+        // Deliver deferred messages before polling for new ones.
+        if let Some((_, front)) = this.stash.pop_front() {
+            if matches!(front, Message::User(_)) {
+                this.counter.fetch_sub(1, Ordering::SeqCst);
+            }
+            return Poll::Ready(Some(front));
+        }
+
+        let user_poll = match &mut this.rx_user {
+            UserReceiver::Unbounded(rx) => rx.poll_recv(cx),
+            UserReceiver::Bounded(q) => poll_bounded_recv(q, cx),
+        };
+        match user_poll {
+            Poll::Ready(Some(p)) => {
+                this.counter.fetch_sub(1, Ordering::SeqCst);
+                Poll::Ready(Some(Message::User(p.data)))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -346,6 +868,27 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn stream_yields_system_messages_before_user_and_drains_stash() {
+        use futures::StreamExt;
+
+        let (tx, mut rx) = channel();
+        tx.send(Message::User(Bytes::from_static(b"m1"))).unwrap();
+        tx.send(Message::System(SystemMessage::Ping)).unwrap();
+
+        let first = rx.next().await.expect("stream should yield a message");
+        assert_eq!(first, Message::System(SystemMessage::Ping));
+
+        let second = rx.next().await.expect("stream should yield a message");
+        match second {
+            Message::User(b) => assert_eq!(b.as_ref(), b"m1"),
+            _ => panic!("expected user message"),
+        }
+
+        drop(tx);
+        assert_eq!(rx.next().await, None);
+    }
+
     #[tokio::test]
     async fn bounded_mailbox_drop_new() {
         // This test will fail until bounded mailbox is implemented.
@@ -408,4 +951,133 @@ mod tests {
             _ => panic!("expected user message"),
         }
     }
+
+    #[tokio::test]
+    async fn trap_exit_defaults_to_on_and_is_toggleable() {
+        let (_tx, rx) = channel();
+        assert!(rx.is_trapping_exit());
+        rx.set_trap_exit(false);
+        assert!(!rx.is_trapping_exit());
+    }
+
+    #[tokio::test]
+    async fn recv_tagged_matches_by_tag_and_preserves_order() {
+        let (tx, mut rx) = channel();
+
+        tx.send_tagged(1, Bytes::from_static(b"reply-1")).unwrap();
+        tx.send_tagged(2, Bytes::from_static(b"reply-2")).unwrap();
+        tx.send_user_bytes(Bytes::from_static(b"untagged")).unwrap();
+
+        // Only tag 2 should match; tag 1 and the untagged message are stashed.
+        let got = rx.recv_tagged(&[2]).await.expect("should find tag 2");
+        match got {
+            Message::User(b) => assert_eq!(b.as_ref(), b"reply-2"),
+            _ => panic!("expected user message"),
+        }
+
+        let first = rx.recv().await.expect("first deferred");
+        let second = rx.recv().await.expect("second deferred");
+        match first {
+            Message::User(b) => assert_eq!(b.as_ref(), b"reply-1"),
+            _ => panic!("expected user message"),
+        }
+        match second {
+            Message::User(b) => assert_eq!(b.as_ref(), b"untagged"),
+            _ => panic!("expected user message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn send_async_suspends_until_capacity_frees() {
+        // Only `MailboxPolicy::Block` suspends on a full queue; `DropNew`
+        // and `DropOldest` always resolve immediately (see the policy's
+        // own tests below).
+        let (tx, mut rx) = bounded_channel_with_policy(1, MailboxPolicy::Block);
+        tx.send(Message::User(Bytes::from_static(b"m1"))).unwrap();
+
+        // The mailbox is full, so this send should not resolve until a slot
+        // is freed by draining `m1` below.
+        let tx2 = tx.clone();
+        let sender = tokio::spawn(async move {
+            tx2.send_async(Message::User(Bytes::from_static(b"m2")))
+                .await
+                .unwrap();
+        });
+
+        // Give the spawned send a chance to run and confirm it is still
+        // pending on the full mailbox.
+        tokio::task::yield_now().await;
+        assert!(!sender.is_finished());
+
+        let first = rx.recv().await.expect("first");
+        match first {
+            Message::User(b) => assert_eq!(b.as_ref(), b"m1"),
+            _ => panic!("expected user message"),
+        }
+
+        sender.await.expect("send_async task should complete");
+        let second = rx.recv().await.expect("second");
+        match second {
+            Message::User(b) => assert_eq!(b.as_ref(), b"m2"),
+            _ => panic!("expected user message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn reserve_only_counts_toward_len_after_commit() {
+        let (tx, mut rx) = bounded_channel(2);
+        let permit = tx.reserve().await.expect("should reserve a slot");
+        assert_eq!(tx.len(), 0, "a reservation alone must not count as queued");
+
+        permit.send(0, Bytes::from_static(b"reserved"));
+        assert_eq!(tx.len(), 1, "committing the permit should count it as queued");
+
+        let got = rx.recv().await.expect("should receive");
+        match got {
+            Message::User(b) => assert_eq!(b.as_ref(), b"reserved"),
+            _ => panic!("expected user message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_policy_evicts_front_and_never_rejects() {
+        let (tx, mut rx) = bounded_channel_with_policy(2, MailboxPolicy::DropOldest);
+        tx.send(Message::User(Bytes::from_static(b"m1"))).unwrap();
+        tx.send(Message::User(Bytes::from_static(b"m2"))).unwrap();
+
+        // The queue is full, but DropOldest must still accept: "m1" is
+        // evicted to make room for "m3".
+        tx.send(Message::User(Bytes::from_static(b"m3")))
+            .expect("DropOldest never rejects");
+        assert_eq!(tx.len(), 2, "eviction should leave the queued count unchanged");
+
+        let first = rx.recv().await.expect("first");
+        let second = rx.recv().await.expect("second");
+        match first {
+            Message::User(b) => assert_eq!(b.as_ref(), b"m2"),
+            _ => panic!("expected user message"),
+        }
+        match second {
+            Message::User(b) => assert_eq!(b.as_ref(), b"m3"),
+            _ => panic!("expected user message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn block_policy_send_rejects_when_full() {
+        let (tx, mut rx) = bounded_channel_with_policy(1, MailboxPolicy::Block);
+        tx.send(Message::User(Bytes::from_static(b"m1"))).unwrap();
+
+        // The non-blocking `send` has no way to wait, so even under `Block`
+        // it rejects a full queue.
+        assert!(tx
+            .send(Message::User(Bytes::from_static(b"m2")))
+            .is_err());
+
+        let got = rx.recv().await.expect("should receive");
+        match got {
+            Message::User(b) => assert_eq!(b.as_ref(), b"m1"),
+            _ => panic!("expected user message"),
+        }
+    }
 }
@@ -1,55 +1,516 @@
 // src/network.rs
 //! Phase 5 & 7: Distributed Networking and Remote Resolution
 
-use crate::mailbox::Message;
+use crate::mailbox::{Message, SystemMessage};
 use crate::pid::Pid;
 use bytes::{Bytes, BytesMut, Buf, BufMut};
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::tcp::OwnedWriteHalf;
 use tokio::net::{TcpListener, TcpStream};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufWriter};
+use tokio::sync::{oneshot, Mutex as TokioMutex, OnceCell};
+
+/// Reply payload for a remote call: the handler's returned bytes, or an
+/// error message if the target pid didn't exist or dropped the reply
+/// without answering. Boxed behind the same raw-pointer `SystemMessage::Call`
+/// trick `PyRuntime::call`/`reply_from_ptr` use locally, so a remote RPC
+/// request is delivered to the target actor exactly like a local `call` —
+/// the actor's dispatch loop doesn't need to know the reply is headed back
+/// over the network instead of to a local oneshot.
+type RemoteCallResult = Result<Vec<u8>, String>;
+
+/// Default write-coalescing threshold: a pooled connection flushes as soon
+/// as its `BufWriter` holds this many buffered bytes, rather than waiting
+/// for the next timer tick.
+const DEFAULT_FLUSH_THRESHOLD_BYTES: usize = 4096;
+
+/// How often a pooled connection's background task flushes whatever is
+/// buffered, even if the byte threshold hasn't been hit — bounds the
+/// latency a chatty-but-small workload would otherwise see from frames
+/// sitting in the `BufWriter` indefinitely.
+const FLUSH_TICK: Duration = Duration::from_millis(2);
+
+/// Sent (and expected back) as the first 4 bytes of every connection,
+/// before any frame traffic. Lets a peer speaking a different protocol
+/// entirely — or no protocol at all — be rejected with a clean error
+/// instead of having its bytes misread as a frame's length field.
+const PROTOCOL_MAGIC: [u8; 4] = *b"IRIS";
+
+/// Bumped whenever `Frame`'s wire format changes incompatibly. Exchanged
+/// right after `PROTOCOL_MAGIC` so two Iris nodes running skewed versions
+/// fail the handshake instead of misinterpreting each other's frames.
+const PROTOCOL_VERSION: u8 = 1;
+
+/// Default ceiling on a single frame's `LEN` field. Without this, a
+/// truncated or adversarial peer could send an arbitrarily large length
+/// ahead of the data and force a matching `vec![0u8; len]` allocation
+/// before the read even has a chance to fail.
+const DEFAULT_MAX_FRAME_BYTES: u32 = 64 * 1024 * 1024;
+
+type PooledConn = Arc<TokioMutex<BufWriter<TcpStream>>>;
+
+/// One parsed wire frame, decoded by `decode_frame` and produced by
+/// `encode_frame`. Replaces the hand-rolled per-call-site byte parsing
+/// `start_server`'s read loop used to do directly against `.unwrap()`ed
+/// `read_exact`s.
+pub(crate) enum Frame {
+    /// Type 0: fire-and-forget user message.
+    Send { pid: Pid, data: Bytes },
+    /// Type 1: resolve `name` on the receiving node. The reply (a bare
+    /// big-endian `u64` pid, `0` meaning "not found") isn't itself framed —
+    /// it was never length-prefixed or type-tagged on the wire, so there's
+    /// nothing for `decode_frame` to validate there.
+    Resolve { name: String },
+    /// Type 2: RPC request awaiting a `CallReply` carrying the same
+    /// `request_id`.
+    CallRequest { request_id: u64, pid: Pid, data: Bytes },
+    /// Type 3: RPC reply to a `CallRequest`.
+    CallReply { request_id: u64, data: Bytes },
+}
+
+fn protocol_error(msg: impl Into<String>) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, msg.into())
+}
+
+/// Encode `frame` into its wire representation (type byte, then the
+/// fixed-width fields, then any variable-length payload last).
+pub(crate) fn encode_frame(frame: &Frame) -> BytesMut {
+    let mut buf = BytesMut::new();
+    match frame {
+        Frame::Send { pid, data } => {
+            buf.put_u8(0);
+            buf.put_u64(*pid);
+            buf.put_u32(data.len() as u32);
+            buf.put_slice(data);
+        }
+        Frame::Resolve { name } => {
+            buf.put_u8(1);
+            buf.put_u32(name.len() as u32);
+            buf.put_slice(name.as_bytes());
+        }
+        Frame::CallRequest { request_id, pid, data } => {
+            buf.put_u8(2);
+            buf.put_u64(*request_id);
+            buf.put_u64(*pid);
+            buf.put_u32(data.len() as u32);
+            buf.put_slice(data);
+        }
+        Frame::CallReply { request_id, data } => {
+            buf.put_u8(3);
+            buf.put_u64(*request_id);
+            buf.put_u32(data.len() as u32);
+            buf.put_slice(data);
+        }
+    }
+    buf
+}
+
+/// Read one length-validated payload off `reader`: `io::Error` (never a
+/// panic) on a short read, and on a `len` over `max_frame_bytes` without
+/// even attempting the allocation.
+async fn read_payload(
+    reader: &mut (impl AsyncReadExt + Unpin),
+    len: u32,
+    max_frame_bytes: u32,
+) -> std::io::Result<Bytes> {
+    if len > max_frame_bytes {
+        return Err(protocol_error(format!(
+            "frame length {len} exceeds the {max_frame_bytes}-byte limit"
+        )));
+    }
+    let mut data = vec![0u8; len as usize];
+    reader.read_exact(&mut data).await?;
+    Ok(Bytes::from(data))
+}
+
+/// Decode one `Frame` off `reader`, validating `LEN` against
+/// `max_frame_bytes` and returning an `io::Result` instead of panicking on
+/// a truncated or malformed frame — the caller tears down just this
+/// connection on error rather than the whole listener.
+pub(crate) async fn decode_frame(
+    reader: &mut (impl AsyncReadExt + Unpin),
+    max_frame_bytes: u32,
+) -> std::io::Result<Frame> {
+    let mut head = [0u8; 1];
+    reader.read_exact(&mut head).await?;
+    match head[0] {
+        0 => {
+            let mut meta = [0u8; 12];
+            reader.read_exact(&mut meta).await?;
+            let mut cursor = std::io::Cursor::new(&meta);
+            let pid = cursor.get_u64();
+            let len = cursor.get_u32();
+            let data = read_payload(reader, len, max_frame_bytes).await?;
+            Ok(Frame::Send { pid, data })
+        }
+        1 => {
+            let mut len_buf = [0u8; 4];
+            reader.read_exact(&mut len_buf).await?;
+            let len = u32::from_be_bytes(len_buf);
+            let data = read_payload(reader, len, max_frame_bytes).await?;
+            let name = String::from_utf8(data.to_vec())
+                .map_err(|_| protocol_error("resolve frame name was not valid utf-8"))?;
+            Ok(Frame::Resolve { name })
+        }
+        2 => {
+            let mut meta = [0u8; 20];
+            reader.read_exact(&mut meta).await?;
+            let mut cursor = std::io::Cursor::new(&meta);
+            let request_id = cursor.get_u64();
+            let pid = cursor.get_u64();
+            let len = cursor.get_u32();
+            let data = read_payload(reader, len, max_frame_bytes).await?;
+            Ok(Frame::CallRequest { request_id, pid, data })
+        }
+        3 => {
+            let mut meta = [0u8; 12];
+            reader.read_exact(&mut meta).await?;
+            let mut cursor = std::io::Cursor::new(&meta);
+            let request_id = cursor.get_u64();
+            let len = cursor.get_u32();
+            let data = read_payload(reader, len, max_frame_bytes).await?;
+            Ok(Frame::CallReply { request_id, data })
+        }
+        other => Err(protocol_error(format!("unknown frame type {other}"))),
+    }
+}
+
+/// Write our magic + version, then check the peer's. Run by both sides of
+/// every new connection before any `Frame` traffic, so a version skew or a
+/// non-Iris peer is rejected cleanly instead of having its bytes
+/// misinterpreted as a frame.
+async fn handshake(
+    writer: &mut (impl AsyncWriteExt + Unpin),
+    reader: &mut (impl AsyncReadExt + Unpin),
+) -> std::io::Result<()> {
+    writer.write_all(&PROTOCOL_MAGIC).await?;
+    writer.write_all(&[PROTOCOL_VERSION]).await?;
+    writer.flush().await?;
+
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).await?;
+    if magic != PROTOCOL_MAGIC {
+        return Err(protocol_error("peer did not send the Iris protocol magic"));
+    }
+    let mut version = [0u8; 1];
+    reader.read_exact(&mut version).await?;
+    if version[0] != PROTOCOL_VERSION {
+        return Err(protocol_error(format!(
+            "peer speaks protocol version {}, we speak {PROTOCOL_VERSION}",
+            version[0]
+        )));
+    }
+    Ok(())
+}
+
+/// A dedicated connection backing `call_remote`, separate from the
+/// fire-and-forget `peers` pool: replies can arrive in any order relative
+/// to requests still being written, so the read half is owned by its own
+/// background task (dispatching each type-3 reply to the matching pending
+/// oneshot by `request_id`) rather than shared behind the same lock as the
+/// write half.
+struct CallConn {
+    writer: TokioMutex<OwnedWriteHalf>,
+    pending: Arc<DashMap<u64, oneshot::Sender<RemoteCallResult>>>,
+}
 
 pub struct NetworkManager {
     runtime: Arc<crate::Runtime>,
+    /// Persistent outbound connections keyed by peer address, so repeated
+    /// `send_remote` calls to the same node reuse one socket instead of
+    /// paying a fresh TCP handshake per message.
+    peers: DashMap<String, Arc<OnceCell<PooledConn>>>,
+    /// Persistent connections backing `call_remote`, kept separate from
+    /// `peers` since they need a dedicated reader task (see `CallConn`).
+    call_conns: DashMap<String, Arc<OnceCell<Arc<CallConn>>>>,
+    next_request_id: AtomicU64,
+    flush_threshold: AtomicUsize,
+    max_frame_bytes: AtomicUsize,
 }
 
 impl NetworkManager {
     pub fn new(runtime: Arc<crate::Runtime>) -> Self {
-        Self { runtime }
+        Self {
+            runtime,
+            peers: DashMap::new(),
+            call_conns: DashMap::new(),
+            next_request_id: AtomicU64::new(1),
+            flush_threshold: AtomicUsize::new(DEFAULT_FLUSH_THRESHOLD_BYTES),
+            max_frame_bytes: AtomicUsize::new(DEFAULT_MAX_FRAME_BYTES as usize),
+        }
+    }
+
+    /// Change the write-coalescing byte threshold used by every pooled
+    /// connection (existing and future).
+    pub fn set_flush_threshold(&self, bytes: usize) {
+        self.flush_threshold.store(bytes.max(1), Ordering::Relaxed);
+    }
+
+    /// Change the ceiling a frame's `LEN` field is validated against
+    /// before `decode_frame` allocates its payload buffer.
+    pub fn set_max_frame_bytes(&self, bytes: usize) {
+        self.max_frame_bytes.store(bytes.max(1), Ordering::Relaxed);
+    }
+
+    fn max_frame_bytes(&self) -> u32 {
+        self.max_frame_bytes.load(Ordering::Relaxed) as u32
+    }
+
+    /// Fetch the pooled connection to `addr`, dialing, handshaking, and
+    /// registering a new one (Nagle disabled, with its own background
+    /// flush ticker) if this is the first send to that peer.
+    ///
+    /// Two concurrent first-sends to the same `addr` both land on the same
+    /// `OnceCell` via `entry().or_insert_with()` (that part of `DashMap` is
+    /// synchronous and atomic), then race on `get_or_try_init`, which only
+    /// lets one of them actually dial — the loser awaits the winner's
+    /// result instead of opening (and leaking) a second socket and ticker
+    /// task.
+    async fn connection(&self, addr: &str) -> std::io::Result<PooledConn> {
+        let cell = self
+            .peers
+            .entry(addr.to_string())
+            .or_insert_with(|| Arc::new(OnceCell::new()))
+            .clone();
+        cell.get_or_try_init(|| async {
+            let mut stream = TcpStream::connect(addr).await?;
+            stream.set_nodelay(true)?;
+            {
+                let (mut read_half, mut write_half) = stream.split();
+                handshake(&mut write_half, &mut read_half).await?;
+            }
+            let conn: PooledConn = Arc::new(TokioMutex::new(BufWriter::new(stream)));
+
+            let ticker_conn = conn.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(FLUSH_TICK);
+                loop {
+                    interval.tick().await;
+                    let mut guard = ticker_conn.lock().await;
+                    if guard.flush().await.is_err() {
+                        break;
+                    }
+                }
+            });
+
+            Ok::<_, std::io::Error>(conn)
+        })
+        .await
+        .cloned()
+    }
+
+    /// Write `frame` to the pooled connection for `addr`, flushing
+    /// immediately once the buffer passes `flush_threshold`; otherwise the
+    /// connection's own ticker flushes it within `FLUSH_TICK`. On a write
+    /// error the dead connection is evicted and one fresh connection is
+    /// dialed and retried, since a pooled socket the peer already closed
+    /// would otherwise fail every subsequent send forever.
+    async fn write_frame(&self, addr: &str, frame: &[u8]) -> std::io::Result<()> {
+        let conn = self.connection(addr).await?;
+        {
+            let mut guard = conn.lock().await;
+            if guard.write_all(frame).await.is_ok() {
+                if guard.buffer().len() >= self.flush_threshold.load(Ordering::Relaxed) {
+                    guard.flush().await?;
+                }
+                return Ok(());
+            }
+        }
+        // Stale/broken connection: drop it from the pool and retry once.
+        self.peers.remove(addr);
+        let conn = self.connection(addr).await?;
+        let mut guard = conn.lock().await;
+        guard.write_all(frame).await?;
+        guard.flush().await
+    }
+
+    /// Fetch the dedicated call connection to `addr`, dialing, handshaking,
+    /// and spawning its reply-dispatch task if this is the first call to
+    /// that peer. Concurrent first-calls are serialized through a shared
+    /// `OnceCell` the same way `connection()` serializes `peers`, so they
+    /// can't race into dialing (and leaking) two sockets and reader tasks.
+    async fn call_connection(&self, addr: &str) -> std::io::Result<Arc<CallConn>> {
+        let cell = self
+            .call_conns
+            .entry(addr.to_string())
+            .or_insert_with(|| Arc::new(OnceCell::new()))
+            .clone();
+        cell.get_or_try_init(|| async {
+            let mut stream = TcpStream::connect(addr).await?;
+            stream.set_nodelay(true)?;
+            {
+                let (mut read_half, mut write_half) = stream.split();
+                handshake(&mut write_half, &mut read_half).await?;
+            }
+            let (mut read_half, write_half) = stream.into_split();
+            let pending: Arc<DashMap<u64, oneshot::Sender<RemoteCallResult>>> =
+                Arc::new(DashMap::new());
+            let conn = Arc::new(CallConn {
+                writer: TokioMutex::new(write_half),
+                pending: pending.clone(),
+            });
+
+            let max_frame_bytes = self.max_frame_bytes();
+            let reader_addr = addr.to_string();
+            tokio::spawn(async move {
+                loop {
+                    match decode_frame(&mut read_half, max_frame_bytes).await {
+                        Ok(Frame::CallReply { request_id, data }) => {
+                            if let Some((_, tx)) = pending.remove(&request_id) {
+                                let _ = tx.send(Ok(data.to_vec()));
+                            }
+                        }
+                        Ok(_) => {
+                            tracing::warn!(addr = %reader_addr, "call connection received a non-reply frame; dropping it");
+                        }
+                        Err(e) => {
+                            tracing::warn!(addr = %reader_addr, error = %e, "call connection closed");
+                            break;
+                        }
+                    }
+                }
+                // Connection dropped: nothing will ever complete the
+                // outstanding oneshots, so `call_remote` would otherwise hang
+                // until its timeout for calls that were in flight.
+                pending.clear();
+            });
+
+            Ok::<_, std::io::Error>(conn)
+        })
+        .await
+        .cloned()
+    }
+
+    /// Send `data` to `pid` on the peer at `addr` and await its typed
+    /// reply, timing out (and forgetting the pending request) after
+    /// `timeout`. The request is delivered to the target actor through the
+    /// same `SystemMessage::Call` path a local `call` uses, so ordinary
+    /// actor behaviors don't need to know whether the caller was local or
+    /// remote.
+    pub async fn call_remote(
+        &self,
+        addr: &str,
+        pid: Pid,
+        data: Bytes,
+        timeout: Duration,
+    ) -> std::io::Result<Bytes> {
+        let conn = self.call_connection(addr).await?;
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = oneshot::channel();
+        conn.pending.insert(request_id, tx);
+
+        let frame = encode_frame(&Frame::CallRequest { request_id, pid, data });
+        let write_result: std::io::Result<()> = async {
+            let mut writer = conn.writer.lock().await;
+            writer.write_all(&frame).await?;
+            writer.flush().await
+        }
+        .await;
+
+        if let Err(e) = write_result {
+            conn.pending.remove(&request_id);
+            self.call_conns.remove(addr);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(Ok(bytes))) => Ok(Bytes::from(bytes)),
+            Ok(Ok(Err(msg))) => Err(std::io::Error::new(std::io::ErrorKind::Other, msg)),
+            Ok(Err(_)) => Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionAborted,
+                "call_remote: connection closed before reply",
+            )),
+            Err(_) => {
+                conn.pending.remove(&request_id);
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "call_remote: timed out waiting for reply",
+                ))
+            }
+        }
     }
 
     pub async fn start_server(&self, addr: &str) -> std::io::Result<()> {
         let listener = TcpListener::bind(addr).await?;
         let rt = self.runtime.clone();
+        let max_frame_bytes = self.max_frame_bytes();
 
         tokio::spawn(async move {
-            while let Ok((mut socket, _)) = listener.accept().await {
+            while let Ok((socket, peer_addr)) = listener.accept().await {
+                let _ = socket.set_nodelay(true);
                 let rt_inner = rt.clone();
                 tokio::spawn(async move {
-                    let mut head = [0u8; 1];
-                    while socket.read_exact(&mut head).await.is_ok() {
-                        match head[0] {
-                            0 => { // User Message: [PID:u64][LEN:u32][DATA]
-                                let mut meta = [0u8; 12];
-                                socket.read_exact(&mut meta).await.unwrap();
-                                let mut cursor = std::io::Cursor::new(&meta);
-                                let pid = cursor.get_u64();
-                                let len = cursor.get_u32() as usize;
-                                let mut data = vec![0u8; len];
-                                socket.read_exact(&mut data).await.unwrap();
-                                let _ = rt_inner.send(pid, Message::User(Bytes::from(data)));
-                            }
-                            1 => { // Resolve Request: [LEN:u32][NAME:String] -> [PID:u64]
-                                let mut len_buf = [0u8; 4];
-                                socket.read_exact(&mut len_buf).await.unwrap();
-                                let len = u32::from_be_bytes(len_buf) as usize;
-                                let mut name_vec = vec![0u8; len];
-                                socket.read_exact(&mut name_vec).await.unwrap();
-                                let name = String::from_utf8_lossy(&name_vec);
+                    // Split so a type-2 RPC request can be answered by a
+                    // separately-spawned task (once the local actor gets
+                    // around to replying) while this loop keeps reading the
+                    // next frame instead of blocking on it.
+                    let (mut read_half, mut write_half) = socket.into_split();
+                    if let Err(e) = handshake(&mut write_half, &mut read_half).await {
+                        tracing::warn!(peer = %peer_addr, error = %e, "rejecting connection: handshake failed");
+                        return;
+                    }
+                    let write_half = Arc::new(TokioMutex::new(write_half));
 
+                    loop {
+                        let frame = match decode_frame(&mut read_half, max_frame_bytes).await {
+                            Ok(frame) => frame,
+                            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                            Err(e) => {
+                                // Tear down only this connection; the
+                                // listener keeps accepting everyone else.
+                                tracing::warn!(peer = %peer_addr, error = %e, "closing connection: malformed frame");
+                                break;
+                            }
+                        };
+                        match frame {
+                            Frame::Send { pid, data } => {
+                                let _ = rt_inner.send(pid, Message::User(data));
+                            }
+                            Frame::Resolve { name } => {
                                 let pid = rt_inner.resolve(&name).unwrap_or(0);
-                                socket.write_all(&pid.to_be_bytes()).await.unwrap();
+                                let mut guard = write_half.lock().await;
+                                if guard.write_all(&pid.to_be_bytes()).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Frame::CallRequest { request_id, pid, data } => {
+                                let (tx, rx) = oneshot::channel::<RemoteCallResult>();
+                                let reply_ptr = Box::into_raw(Box::new(tx)) as usize;
+                                let msg = Message::System(SystemMessage::Call(data, reply_ptr));
+                                if rt_inner.send(pid, msg).is_err() {
+                                    // No such actor; reclaim the boxed sender
+                                    // and answer with the same failure a local
+                                    // `call` would see.
+                                    let tx = unsafe {
+                                        *Box::from_raw(
+                                            reply_ptr as *mut oneshot::Sender<RemoteCallResult>,
+                                        )
+                                    };
+                                    let _ = tx.send(Err("no such pid".to_string()));
+                                }
+
+                                let reply_write_half = write_half.clone();
+                                tokio::spawn(async move {
+                                    let result = rx
+                                        .await
+                                        .unwrap_or_else(|_| Err("actor dropped reply".to_string()));
+                                    let payload = result.unwrap_or_else(|e| e.into_bytes());
+                                    let frame = encode_frame(&Frame::CallReply {
+                                        request_id,
+                                        data: Bytes::from(payload),
+                                    });
+                                    let mut guard = reply_write_half.lock().await;
+                                    let _ = guard.write_all(&frame).await;
+                                    let _ = guard.flush().await;
+                                });
+                            }
+                            Frame::CallReply { .. } => {
+                                tracing::warn!(peer = %peer_addr, "closing connection: client sent a server-only frame type");
+                                break;
                             }
-                            _ => break,
                         }
                     }
                 });
@@ -58,26 +519,119 @@ impl NetworkManager {
         Ok(())
     }
 
+    /// Resolve `name` on the peer at `addr`. Synchronous request/reply, so
+    /// unlike `send_remote` it flushes immediately rather than coalescing —
+    /// the caller is already waiting on the reply either way. On failure
+    /// the pooled connection is evicted and one fresh connection is dialed
+    /// and retried, the same eviction `write_frame` already does, since a
+    /// pooled socket the peer already closed would otherwise break every
+    /// subsequent resolve to that peer forever.
     pub async fn resolve_remote(&self, addr: &str, name: &str) -> std::io::Result<Pid> {
-        let mut stream = TcpStream::connect(addr).await?;
-        stream.write_all(&[1u8]).await?; // Type 1: Resolve
-        let name_bytes = name.as_bytes();
-        stream.write_all(&(name_bytes.len() as u32).to_be_bytes()).await?;
-        stream.write_all(name_bytes).await?;
+        let frame = encode_frame(&Frame::Resolve { name: name.to_string() });
+
+        let conn = self.connection(addr).await?;
+        if let Ok(pid) = Self::resolve_on(&conn, &frame).await {
+            return Ok(pid);
+        }
 
+        self.peers.remove(addr);
+        let conn = self.connection(addr).await?;
+        Self::resolve_on(&conn, &frame).await
+    }
+
+    /// Send an already-encoded `Frame::Resolve` over `conn` and read back
+    /// its bare-pid reply. Split out of `resolve_remote` so it can be
+    /// retried against a freshly-dialed connection without duplicating the
+    /// write/flush/read sequence.
+    async fn resolve_on(conn: &PooledConn, frame: &[u8]) -> std::io::Result<Pid> {
+        let mut guard = conn.lock().await;
+        guard.write_all(frame).await?;
+        guard.flush().await?;
+
+        // The reply is a bare pid, not a `Frame` — see `Frame::Resolve`'s
+        // doc comment.
         let mut pid_buf = [0u8; 8];
-        stream.read_exact(&mut pid_buf).await?;
+        guard.get_mut().read_exact(&mut pid_buf).await?;
         Ok(u64::from_be_bytes(pid_buf))
     }
 
+    /// Fire-and-forget send to `pid` on the peer at `addr`, over a pooled,
+    /// Nagle-disabled connection. The frame is coalesced with other
+    /// outstanding writes to the same peer rather than flushed immediately;
+    /// see `write_frame`.
     pub async fn send_remote(&self, addr: &str, pid: Pid, data: Bytes) -> std::io::Result<()> {
-        let mut stream = TcpStream::connect(addr).await?;
-        stream.write_all(&[0u8]).await?; // Type 0: Send
-        let mut buf = BytesMut::with_capacity(12 + data.len());
-        buf.put_u64(pid);
-        buf.put_u32(data.len() as u32);
-        buf.put(data);
-        stream.write_all(&buf).await?;
-        Ok(())
+        let frame = encode_frame(&Frame::Send { pid, data });
+        self.write_frame(addr, &frame).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Accept every incoming connection, perform the handshake as the peer
+    /// side on each, then keep the socket open (dropping it on task exit) so a test can dial
+    /// `addr` with `NetworkManager::connection`/`call_connection` without a
+    /// full Iris node on the other end.
+    async fn spawn_handshake_echo_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+                tokio::spawn(async move {
+                    let (mut read_half, mut write_half) = socket.split();
+                    let _ = handshake(&mut write_half, &mut read_half).await;
+                    // Hold the connection open; the test only cares that a
+                    // dial succeeds and that concurrent dials are deduped.
+                    let mut buf = [0u8; 1];
+                    let _ = read_half.read(&mut buf).await;
+                });
+            }
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn concurrent_first_dials_to_same_addr_share_one_pooled_connection() {
+        let addr = spawn_handshake_echo_server().await;
+        let manager = Arc::new(NetworkManager::new(Arc::new(crate::Runtime::new())));
+
+        let mut dials = Vec::new();
+        for _ in 0..8 {
+            let manager = manager.clone();
+            let addr = addr.clone();
+            dials.push(tokio::spawn(
+                async move { manager.connection(&addr).await.unwrap() },
+            ));
+        }
+
+        let mut conns = Vec::new();
+        for dial in dials {
+            conns.push(dial.await.unwrap());
+        }
+
+        // Every racer must have been handed the exact same pooled
+        // connection — if the get-or-insert had raced, some of them would
+        // hold distinct `Arc`s over distinct sockets instead.
+        let first = Arc::as_ptr(&conns[0]);
+        for conn in &conns[1..] {
+            assert_eq!(Arc::as_ptr(conn), first);
+        }
+        assert_eq!(manager.peers.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn connection_after_eviction_dials_a_fresh_socket() {
+        let addr = spawn_handshake_echo_server().await;
+        let manager = NetworkManager::new(Arc::new(crate::Runtime::new()));
+
+        let first = manager.connection(&addr).await.unwrap();
+        manager.peers.remove(&addr);
+        let second = manager.connection(&addr).await.unwrap();
+
+        assert_ne!(Arc::as_ptr(&first), Arc::as_ptr(&second));
     }
 }
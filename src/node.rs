@@ -30,6 +30,15 @@ fn message_to_js(env: &Env, msg: Message) -> Result<JsUnknown> {
                 SystemMessage::HotSwap(_) => ("HOT_SWAP".to_string(), None),
                 SystemMessage::Ping => ("PING".to_string(), None),
                 SystemMessage::Pong => ("PONG".to_string(), None),
+                SystemMessage::Call(..) => ("CALL".to_string(), None),
+                // Dataspace/monitor messages carry a handle (and, for
+                // Assert, a value) that JsSystemMessage has no field for
+                // yet; surface the type and, for Down, the target pid, and
+                // leave the rest for a future JsSystemMessage extension.
+                SystemMessage::Assert { .. } => ("ASSERT".to_string(), None),
+                SystemMessage::Retract { .. } => ("RETRACT".to_string(), None),
+                SystemMessage::Synced => ("SYNCED".to_string(), None),
+                SystemMessage::Down { pid, .. } => ("DOWN".to_string(), Some(pid as i64)),
             };
             let obj = JsSystemMessage { type_name, target_pid };
             env.to_js_value(&obj)
@@ -37,6 +46,41 @@ fn message_to_js(env: &Env, msg: Message) -> Result<JsUnknown> {
     }
 }
 
+/// --- Registry Event Wrapper ---
+
+#[napi]
+#[derive(Clone)]
+pub struct JsRegistryEvent {
+    pub type_name: String,
+    pub name: String,
+    pub pid: Option<i64>,
+    pub old_pid: Option<i64>,
+}
+
+fn registry_event_to_js(event: crate::registry::RegistryEvent) -> JsRegistryEvent {
+    use crate::registry::RegistryEvent;
+    match event {
+        RegistryEvent::Registered { name, pid } => JsRegistryEvent {
+            type_name: "REGISTERED".to_string(),
+            name,
+            pid: Some(u64::from(pid) as i64),
+            old_pid: None,
+        },
+        RegistryEvent::Replaced { name, old, new } => JsRegistryEvent {
+            type_name: "REPLACED".to_string(),
+            name,
+            pid: Some(u64::from(new) as i64),
+            old_pid: Some(u64::from(old) as i64),
+        },
+        RegistryEvent::Unregistered { name, pid } => JsRegistryEvent {
+            type_name: "UNREGISTERED".to_string(),
+            name,
+            pid: Some(u64::from(pid) as i64),
+            old_pid: None,
+        },
+    }
+}
+
 /// --- Mailbox Wrapper ---
 
 #[napi]
@@ -91,6 +135,14 @@ impl From<Message> for WrappedMessage {
                     SystemMessage::HotSwap(_) => ("HOT_SWAP".to_string(), None),
                     SystemMessage::Ping => ("PING".to_string(), None),
                     SystemMessage::Pong => ("PONG".to_string(), None),
+                    SystemMessage::Call(..) => ("CALL".to_string(), None),
+                    // See message_to_js's matching arms: JsSystemMessage
+                    // has no handle/value field yet, so only the type (and,
+                    // for Down, the target pid) is surfaced here.
+                    SystemMessage::Assert { .. } => ("ASSERT".to_string(), None),
+                    SystemMessage::Retract { .. } => ("RETRACT".to_string(), None),
+                    SystemMessage::Synced => ("SYNCED".to_string(), None),
+                    SystemMessage::Down { pid, .. } => ("DOWN".to_string(), Some(pid as i64)),
                 };
                 WrappedMessage {
                     data: None,
@@ -189,6 +241,31 @@ impl NodeRuntime {
         self.inner.resolve_remote_async(addr, name).await.map(|p| p as i64)
     }
 
+    /// Resolve `name`, parking until it's registered if it isn't yet.
+    #[napi]
+    pub async fn await_name(&self, name: String) -> i64 {
+        self.inner.await_name(name).await as i64
+    }
+
+    /// Forward every registry mutation (registrations/unregistrations) to
+    /// `callback` for as long as the event stream stays open.
+    #[napi]
+    pub fn on_registry_event(&self, callback: JsFunction) -> Result<()> {
+        use futures::StreamExt;
+
+        let tsfn: ThreadsafeFunction<JsRegistryEvent, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+
+        let mut stream = self.inner.registry_events();
+        tokio::spawn(async move {
+            while let Some(event) = stream.next().await {
+                tsfn.call(registry_event_to_js(event), ThreadsafeFunctionCallMode::NonBlocking);
+            }
+        });
+
+        Ok(())
+    }
+
     #[napi]
     pub fn listen(&self, addr: String) {
         self.inner.listen(addr);
@@ -199,9 +276,46 @@ impl NodeRuntime {
         self.inner.send_remote(addr, pid as u64, bytes::Bytes::from(data.to_vec()));
     }
     
+    /// Monitor a remote PID. When `interval_ms`/`timeout_ms` are given, an
+    /// active heartbeat is layered on top of the connection watch: `Ping`s
+    /// are sent at `interval_ms` and a missing `Pong` within `timeout_ms`
+    /// for `max_missed` consecutive attempts synthesizes a `Timeout` exit
+    /// instead of waiting for the socket to drop.
+    #[napi]
+    pub fn monitor_remote(
+        &self,
+        addr: String,
+        pid: i64,
+        interval_ms: Option<u32>,
+        timeout_ms: Option<u32>,
+        max_missed: Option<u32>,
+    ) {
+        match (interval_ms, timeout_ms) {
+            (Some(interval), Some(timeout)) => {
+                self.inner.monitor_remote_heartbeat(
+                    addr,
+                    pid as u64,
+                    std::time::Duration::from_millis(interval as u64),
+                    std::time::Duration::from_millis(timeout as u64),
+                    max_missed.unwrap_or(3),
+                );
+            }
+            _ => self.inner.monitor_remote(addr, pid as u64),
+        }
+    }
+
+    /// Supervise a local actor with an active heartbeat: a `Ping` is sent
+    /// every `interval_ms`, a `Pong` is expected within `timeout_ms`, and
+    /// after `max_missed` consecutive unanswered pings a `Timeout` exit is
+    /// delivered to linked/monitoring actors.
     #[napi]
-    pub fn monitor_remote(&self, addr: String, pid: i64) {
-        self.inner.monitor_remote(addr, pid as u64);
+    pub fn monitor_heartbeat(&self, pid: i64, interval_ms: u32, timeout_ms: u32, max_missed: Option<u32>) {
+        self.inner.monitor_heartbeat(
+            pid as u64,
+            std::time::Duration::from_millis(interval_ms as u64),
+            std::time::Duration::from_millis(timeout_ms as u64),
+            max_missed.unwrap_or(3),
+        );
     }
 
     #[napi]
@@ -0,0 +1,116 @@
+// src/py/codec.rs
+//! MessagePack codec for `Message::User` payloads.
+//!
+//! Plain `send`/`recv` hand Python raw `bytes` and leave (de)serialization to
+//! the caller. `send_obj`/`recv_obj` instead round-trip arbitrary Python
+//! values (None, bool, int, float, str, bytes, list/tuple, dict) through
+//! MessagePack in Rust, modeled on lunatic's `rmp_serde`-based mailbox.
+#![allow(non_local_definitions)]
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple};
+use rmpv::Value;
+
+/// Raised when a received frame does not decode as valid MessagePack
+/// (corrupt or truncated bytes). Bytes after the first complete value are
+/// not checked.
+pyo3::create_exception!(iris, MsgpackDecodeError, pyo3::exceptions::PyValueError);
+
+/// Serialize an arbitrary Python object to a MessagePack byte buffer.
+pub(crate) fn py_to_msgpack(py: Python, obj: &PyAny) -> PyResult<Vec<u8>> {
+    let value = py_to_value(py, obj)?;
+    let mut buf = Vec::new();
+    rmpv::encode::write_value(&mut buf, &value)
+        .map_err(|e| PyValueError::new_err(format!("failed to encode MessagePack: {}", e)))?;
+    Ok(buf)
+}
+
+/// Decode a MessagePack byte buffer into a Python object, raising
+/// `MsgpackDecodeError` with a clear message on malformed frames rather than
+/// panicking or returning a confusing Rust error.
+pub(crate) fn msgpack_to_py(py: Python, bytes: &[u8]) -> PyResult<PyObject> {
+    let mut cursor = bytes;
+    let value = rmpv::decode::read_value(&mut cursor).map_err(|e| {
+        MsgpackDecodeError::new_err(format!("malformed MessagePack frame: {}", e))
+    })?;
+    Ok(value_to_py(py, &value))
+}
+
+fn py_to_value(py: Python, obj: &PyAny) -> PyResult<Value> {
+    if obj.is_none() {
+        return Ok(Value::Nil);
+    }
+    if let Ok(b) = obj.downcast::<PyBool>() {
+        return Ok(Value::Boolean(b.is_true()));
+    }
+    if let Ok(i) = obj.downcast::<PyLong>() {
+        return Ok(Value::from(i.extract::<i64>()?));
+    }
+    if let Ok(f) = obj.downcast::<PyFloat>() {
+        return Ok(Value::F64(f.extract::<f64>()?));
+    }
+    if let Ok(s) = obj.downcast::<PyString>() {
+        return Ok(Value::from(s.to_str()?));
+    }
+    if let Ok(b) = obj.downcast::<PyBytes>() {
+        return Ok(Value::Binary(b.as_bytes().to_vec()));
+    }
+    if let Ok(list) = obj.downcast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| py_to_value(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(Value::Array(items));
+    }
+    if let Ok(tuple) = obj.downcast::<PyTuple>() {
+        let items = tuple
+            .iter()
+            .map(|item| py_to_value(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(Value::Array(items));
+    }
+    if let Ok(dict) = obj.downcast::<PyDict>() {
+        let mut entries = Vec::with_capacity(dict.len());
+        for (k, v) in dict.iter() {
+            entries.push((py_to_value(py, k)?, py_to_value(py, v)?));
+        }
+        return Ok(Value::Map(entries));
+    }
+    Err(PyTypeError::new_err(format!(
+        "send_obj: unsupported type {} (only None/bool/int/float/str/bytes/list/tuple/dict are supported)",
+        obj.get_type().name()?
+    )))
+}
+
+fn value_to_py(py: Python, value: &Value) -> PyObject {
+    match value {
+        Value::Nil => py.None(),
+        Value::Boolean(b) => b.into_py(py),
+        Value::Integer(i) => {
+            if let Some(v) = i.as_i64() {
+                v.into_py(py)
+            } else if let Some(v) = i.as_u64() {
+                v.into_py(py)
+            } else {
+                0i64.into_py(py)
+            }
+        }
+        Value::F32(f) => (*f as f64).into_py(py),
+        Value::F64(f) => f.into_py(py),
+        Value::String(s) => s.as_str().unwrap_or("").into_py(py),
+        Value::Binary(b) => PyBytes::new(py, b).into_py(py),
+        Value::Array(items) => {
+            let converted: Vec<PyObject> = items.iter().map(|v| value_to_py(py, v)).collect();
+            converted.into_py(py)
+        }
+        Value::Map(entries) => {
+            let dict = PyDict::new(py);
+            for (k, v) in entries {
+                let _ = dict.set_item(value_to_py(py, k), value_to_py(py, v));
+            }
+            dict.into_py(py)
+        }
+        Value::Ext(_, data) => PyBytes::new(py, data).into_py(py),
+    }
+}
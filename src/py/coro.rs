@@ -0,0 +1,150 @@
+// src/py/coro.rs
+//! Coroutine (`async def`) Python actors, multiplexed on a small pool of
+//! `tokio::task::LocalSet` worker threads instead of one OS thread per actor.
+//!
+//! `spawn_py_handler` only invokes a synchronous callable once per message,
+//! and `spawn_with_mailbox` burns a whole OS thread per actor on
+//! `spawn_blocking` for its blocking Python loop — neither lets an actor
+//! `await` network/db I/O without tying up a thread for the duration.
+//! `spawn_py_async_handler` instead treats the actor's callback as
+//! `async def`: each message converts the resulting Python coroutine to a
+//! Rust future via `pyo3_asyncio::tokio::into_future` and awaits it inline
+//! in the actor's loop. Because that future (and the Python objects it
+//! closes over) is `!Send`, it can't go through `tokio::spawn` the way an
+//! ordinary actor handler does; it's driven with `spawn_local` on a
+//! dedicated single-threaded runtime + `LocalSet` instead, the same
+//! pattern tokio's own docs use for `!Send` work. A handful of these
+//! worker threads can multiplex hundreds of coroutine actors.
+#![allow(non_local_definitions)]
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use tokio::sync::mpsc;
+
+use crate::mailbox::{Message, MailboxReceiver, SystemMessage};
+
+/// Work handed from `spawn_py_async_handler` to a `LocalSetPool` worker.
+/// Carries the ingredients for the actor's loop (all `Send`) rather than
+/// the loop's future itself, since that future is built — and must stay —
+/// on the worker thread that owns the `LocalSet` it runs on. `done` is
+/// fired when the actor's mailbox closes, so the `Runtime`-side task that
+/// dispatched this (and that the runtime's supervision treats as "the
+/// actor") knows to complete too.
+pub(crate) enum LocalSetTask {
+    Actor {
+        coro_fn: PyObject,
+        rx: MailboxReceiver,
+        done: tokio::sync::oneshot::Sender<()>,
+    },
+}
+
+/// Default number of `LocalSet` worker threads backing the shared
+/// coroutine-actor pool, mirroring `GilPool`'s `DEFAULT_GIL_POOL_SIZE`.
+const DEFAULT_LOCAL_SET_WORKERS: usize = 8;
+
+/// Pool of single-threaded tokio runtimes, each driving one `LocalSet`.
+/// Coroutine actors are assigned to workers round-robin; once assigned, an
+/// actor's entire lifetime (every message) runs on that one worker.
+pub(crate) struct LocalSetPool {
+    workers: Vec<mpsc::UnboundedSender<LocalSetTask>>,
+    next: AtomicUsize,
+}
+
+impl LocalSetPool {
+    fn new(size: usize) -> Self {
+        let workers = (0..size.max(1)).map(|_| spawn_local_set_worker()).collect();
+        LocalSetPool { workers, next: AtomicUsize::new(0) }
+    }
+
+    /// Hand `task` to the next worker in round-robin order.
+    pub(crate) fn dispatch(&self, task: LocalSetTask) {
+        let i = self.next.fetch_add(1, Ordering::Relaxed) % self.workers.len();
+        let _ = self.workers[i].send(task);
+    }
+}
+
+static LOCAL_SET_POOL: OnceLock<LocalSetPool> = OnceLock::new();
+
+/// Lazily create (on first coroutine actor spawn) and return the
+/// process-wide `LocalSetPool`, mirroring `GIL_WORKER_POOL`/
+/// `SUB_INTERPRETER_POOL`.
+pub(crate) fn local_set_pool() -> &'static LocalSetPool {
+    LOCAL_SET_POOL.get_or_init(|| LocalSetPool::new(DEFAULT_LOCAL_SET_WORKERS))
+}
+
+/// Spawn one worker thread: a single-threaded tokio runtime driving a
+/// `LocalSet` that `spawn_local`s one `run_async_actor` task per assigned
+/// actor, plus the unbounded-channel loop that accepts new assignments.
+fn spawn_local_set_worker() -> mpsc::UnboundedSender<LocalSetTask> {
+    let (tx, mut rx) = mpsc::unbounded_channel::<LocalSetTask>();
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build current-thread tokio runtime for coroutine actor worker");
+        let local = tokio::task::LocalSet::new();
+        local.block_on(&rt, async move {
+            while let Some(task) = rx.recv().await {
+                match task {
+                    LocalSetTask::Actor { coro_fn, rx, done } => {
+                        tokio::task::spawn_local(run_async_actor(coro_fn, rx, done));
+                    }
+                }
+            }
+        });
+    });
+    tx
+}
+
+/// Call `coro_fn(bytes)`, convert the returned Python coroutine to a Rust
+/// future via `pyo3_asyncio`, and await it to completion. The GIL is held
+/// only to build the coroutine and to extract it into a future — not
+/// across the `await` itself, so other work on this worker's `LocalSet`
+/// (and other threads entirely) can make progress while this actor awaits.
+async fn call_coro(coro_fn: &PyObject, bytes: bytes::Bytes) -> PyResult<()> {
+    let fut = Python::with_gil(|py| {
+        let pybytes = PyBytes::new(py, &bytes);
+        let coro = coro_fn.as_ref(py).call1((pybytes,))?;
+        pyo3_asyncio::tokio::into_future(coro)
+    })?;
+    fut.await?;
+    Ok(())
+}
+
+/// Body of one coroutine actor: `coro_fn` is called (and awaited) once per
+/// `User` message. `HotSwap` replaces `coro_fn` outright, same as the
+/// synchronous handlers; there is no `Arc<RwLock<..>>` to contend since
+/// this loop is the only task that ever touches it. Fires `done` once the
+/// mailbox closes (or the interpreter starts shutting down), so the
+/// `Runtime`-side task waiting on it can complete.
+async fn run_async_actor(
+    mut coro_fn: PyObject,
+    mut rx: MailboxReceiver,
+    done: tokio::sync::oneshot::Sender<()>,
+) {
+    loop {
+        if unsafe { pyo3::ffi::Py_IsInitialized() } == 0 {
+            break;
+        }
+        let Some(msg) = rx.recv().await else { break };
+        match msg {
+            Message::User(bytes) => {
+                if let Err(e) = call_coro(&coro_fn, bytes).await {
+                    Python::with_gil(|py| {
+                        eprintln!("[Iris] Python async actor exception: {}", e);
+                        e.print(py);
+                    });
+                }
+            }
+            Message::System(SystemMessage::HotSwap(ptr)) => {
+                coro_fn = Python::with_gil(|py| unsafe {
+                    PyObject::from_owned_ptr(py, ptr as *mut pyo3::ffi::PyObject)
+                });
+            }
+            _ => {}
+        }
+    }
+    let _ = done.send(());
+}
@@ -7,7 +7,7 @@
 
 #![allow(non_local_definitions)]
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 
 #[cfg(feature = "pyo3")]
@@ -20,12 +20,107 @@ use cranelift::prelude::*;
 use cranelift_jit::{JITBuilder, JITModule};
 use cranelift_module::{Linkage, Module};
 use pyo3::AsPyPointer;
+use std::path::{Path, PathBuf};
+
+// Embedding map ---------------------------------------------------------------
+//
+// A monotonic-key registry for objects/callables/strings that cross the
+// offload boundary, modeled on nac3's RPC `EmbeddingMap`. Keying the JIT
+// registry and offload tasks by these stable keys instead of
+// `obj.as_ptr() as usize` closes a real hazard: a bare pointer can be
+// reused by an unrelated object once the original is garbage-collected, so
+// a registry keyed on it risks a false match. Interning holds a strong
+// `Py<PyAny>` reference for as long as the key lives, which pins the
+// address and makes that impossible — and, as a side effect, dedups
+// repeated registrations of the same object.
+struct EmbeddingMap {
+    next_key: usize,
+    functions: HashMap<usize, Py<PyAny>>,
+    function_ids: HashMap<usize, usize>,
+    strings: HashMap<usize, String>,
+    string_ids: HashMap<String, usize>,
+    objects: HashMap<usize, Py<PyAny>>,
+    object_ids: HashMap<usize, usize>,
+}
+
+impl EmbeddingMap {
+    fn new() -> Self {
+        EmbeddingMap {
+            next_key: 0,
+            functions: HashMap::new(),
+            function_ids: HashMap::new(),
+            strings: HashMap::new(),
+            string_ids: HashMap::new(),
+            objects: HashMap::new(),
+            object_ids: HashMap::new(),
+        }
+    }
+
+    fn next_key(&mut self) -> usize {
+        let key = self.next_key;
+        self.next_key += 1;
+        key
+    }
+
+    /// Intern `func`, deduping on its current `id()` so re-registering the
+    /// same function object returns the key it was already given.
+    fn intern_function(&mut self, py: Python, func: &PyAny) -> usize {
+        let obj_id = func.as_ptr() as usize;
+        if let Some(&key) = self.function_ids.get(&obj_id) {
+            return key;
+        }
+        let key = self.next_key();
+        self.function_ids.insert(obj_id, key);
+        self.functions.insert(key, func.into_py(py));
+        key
+    }
+
+    fn function(&self, key: usize) -> Option<Py<PyAny>> {
+        self.functions.get(&key).cloned()
+    }
+
+    fn intern_string(&mut self, s: &str) -> usize {
+        if let Some(&key) = self.string_ids.get(s) {
+            return key;
+        }
+        let key = self.next_key();
+        self.string_ids.insert(s.to_owned(), key);
+        self.strings.insert(key, s.to_owned());
+        key
+    }
+
+    /// Intern an arbitrary Python object (e.g. call args/kwargs), deduping
+    /// on `id()` the same way `intern_function` does.
+    fn intern_object(&mut self, py: Python, obj: &PyAny) -> usize {
+        let obj_id = obj.as_ptr() as usize;
+        if let Some(&key) = self.object_ids.get(&obj_id) {
+            return key;
+        }
+        let key = self.next_key();
+        self.object_ids.insert(obj_id, key);
+        self.objects.insert(key, obj.into_py(py));
+        key
+    }
+
+    fn object(&self, key: usize) -> Option<Py<PyAny>> {
+        self.objects.get(&key).cloned()
+    }
+}
+
+static EMBEDDING_MAP: once_cell::sync::OnceCell<Mutex<EmbeddingMap>> = once_cell::sync::OnceCell::new();
+
+fn embedding_map() -> &'static Mutex<EmbeddingMap> {
+    EMBEDDING_MAP.get_or_init(|| Mutex::new(EmbeddingMap::new()))
+}
 
 // Offload actor pool ---------------------------------------------------------
 
-/// A task describing a Python call to execute.
+/// A task describing a Python call to execute. Carries the callable's
+/// embedding-map key rather than a freshly cloned `Py<PyAny>` — the map
+/// already holds the function alive, so dispatch doesn't need its own
+/// strong ref.
 struct OffloadTask {
-    func: Py<PyAny>,
+    func_key: usize,
     args: Py<PyTuple>,
     kwargs: Option<Py<PyDict>>,
     resp: std::sync::mpsc::Sender<Result<PyObject, PyErr>>,
@@ -49,7 +144,14 @@ impl OffloadPool {
                                 break;
                             }
                             Python::with_gil(|py| {
-                                let func = task.func.as_ref(py);
+                                let func_obj = embedding_map().lock().unwrap().function(task.func_key);
+                                let Some(func_obj) = func_obj else {
+                                    let _ = task.resp.send(Err(pyo3::exceptions::PyRuntimeError::new_err(
+                                        "offload task's function key was never registered",
+                                    )));
+                                    return;
+                                };
+                                let func = func_obj.as_ref(py);
                                 let args = task.args.as_ref(py);
                                 let kwargs = task.kwargs.as_ref().map(|k: &Py<PyDict>| k.as_ref(py));
                                 let result = func.call(args, kwargs).map(|obj| obj.into_py(py));
@@ -77,10 +179,107 @@ fn get_offload_pool() -> Arc<OffloadPool> {
 
 // JIT registry -------------------------------------------------------------
 
+/// The concrete scalar type a JIT argument or expression node carries.
+/// `Bool` is a distinct inference target (comparisons/boolean ops yield it,
+/// and `if`/`else` branches must unify through it) but is marshaled and
+/// boxed identically to `I64` at the Python boundary, since Python `bool`
+/// is already an `int` subclass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ty {
+    I64,
+    F64,
+    Bool,
+}
+
+impl Ty {
+    /// The Cranelift type used to hold a value of this type inside the
+    /// generated function body.
+    fn cl_type(self) -> types::Type {
+        match self {
+            Ty::I64 => types::I64,
+            Ty::F64 => types::F64,
+            Ty::Bool => types::I8,
+        }
+    }
+}
+
 #[derive(Clone)]
 struct JitEntry {
     func_ptr: usize,
     arg_count: usize,
+    /// Inferred type of each argument, in declaration order. Drives whether
+    /// `execute_jit_func` unpacks a scalar via `PyFloat_AsDouble` or
+    /// `PyLong_AsLongLong`.
+    arg_types: Vec<Ty>,
+    /// Inferred return type of the compiled expression. `Bool` is boxed the
+    /// same way as `I64` (a Python int).
+    ret_type: Ty,
+    /// Address and lane count of the vectorized buffer-path kernel, when one
+    /// could be built (single f64 arg, f64 return, arithmetic-only
+    /// expression — see `is_vectorizable`). The kernel reads `lanes`
+    /// contiguous elements from an input pointer and writes `lanes` results
+    /// to an output pointer; `None` means the buffer path must fall back to
+    /// calling `func_ptr` once per element.
+    vector_entry: Option<(usize, usize)>,
+    /// Target this entry was compiled for. Always `Host` for the in-process
+    /// `JITModule` path; recorded explicitly for AOT entries loaded from the
+    /// on-disk cache. Nothing reads it back: a later `register_offload` call
+    /// compiles (or loads from the cache, keyed by `target`/`opt_level`) and
+    /// replaces the registry entry for that key regardless.
+    target: JitTarget,
+    opt_level: String,
+}
+
+/// Target ISA selection for compiled JIT functions, mirroring nac3's `Isa`
+/// enum: `Host` runs in-process via the default `JITBuilder` triple, the
+/// rest name explicit cross-compilation targets for the AOT object-file
+/// path (`compile_jit_object`). Cross targets can be emitted to a cache
+/// directory for another toolchain to link, but — since they don't match
+/// this process's architecture — never get a runnable `JitEntry` here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum JitTarget {
+    Host,
+    X86_64,
+    Aarch64,
+    RiscV32,
+}
+
+impl JitTarget {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "host" => Some(JitTarget::Host),
+            "x86_64" => Some(JitTarget::X86_64),
+            "aarch64" => Some(JitTarget::Aarch64),
+            "riscv32" => Some(JitTarget::RiscV32),
+            _ => None,
+        }
+    }
+
+    /// True only for `Host`: the only target whose emitted object code runs
+    /// on this process's architecture and can be `dlopen`ed back into it.
+    fn is_host(self) -> bool {
+        self == JitTarget::Host
+    }
+
+    fn triple(self) -> target_lexicon::Triple {
+        match self {
+            JitTarget::Host => target_lexicon::Triple::host(),
+            JitTarget::X86_64 => "x86_64-unknown-linux-gnu".parse().unwrap(),
+            JitTarget::Aarch64 => "aarch64-unknown-linux-gnu".parse().unwrap(),
+            JitTarget::RiscV32 => "riscv32gc-unknown-linux-gnu".parse().unwrap(),
+        }
+    }
+}
+
+impl std::fmt::Display for JitTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            JitTarget::Host => "host",
+            JitTarget::X86_64 => "x86_64",
+            JitTarget::Aarch64 => "aarch64",
+            JitTarget::RiscV32 => "riscv32",
+        })
+    }
 }
 
 static JIT_REGISTRY: once_cell::sync::OnceCell<Mutex<HashMap<usize, JitEntry>>> =
@@ -98,196 +297,1129 @@ fn lookup_jit(func_key: usize) -> Option<JitEntry> {
         .and_then(|map| map.lock().unwrap().get(&func_key).cloned())
 }
 
+/// Comparison operators supported inside JIT expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Lt,
+    Le,
+    Eq,
+    Gt,
+    Ge,
+}
+
+/// Short-circuit-free boolean combinators (operands are already scalars by
+/// the time they reach `BoolOp`, so both sides are always evaluated).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoolOpKind {
+    And,
+    Or,
+}
+
 // simple expression AST for compiler
 #[derive(Debug, Clone)]
 enum Expr {
     Const(f64),
+    Int(i64),
     Var(String),
     BinOp(Box<Expr>, char, Box<Expr>),
+    Compare(Box<Expr>, CompareOp, Box<Expr>),
+    BoolOp(Box<Expr>, BoolOpKind, Box<Expr>),
+    /// `then if cond else else_` — stored in evaluation order (condition
+    /// first) regardless of Python's surface syntax.
+    IfExpr(Box<Expr>, Box<Expr>, Box<Expr>),
+    /// A call to one of the transcendental functions in `math_fn_ptr`.
+    /// Unknown names are rejected by `validate_calls` before codegen.
+    Call(String, Vec<Expr>),
 }
 
 // parser helpers
-fn tokenize(expr: &str) -> Vec<String> {
+
+/// A token plus the byte range in the original `source_expr` it came from,
+/// so a parse failure can point `IrisJitError` at the exact offending text
+/// instead of just naming it.
+#[derive(Debug, Clone)]
+struct Token {
+    text: String,
+    span: (usize, usize),
+}
+
+/// A compile failure with enough detail for `register_offload` to raise a
+/// real `IrisJitError` instead of the old silent `eprintln!`-and-fall-back
+/// behavior: a byte span into `source_expr` and a human-readable message.
+/// Emitted by the tokenizer/parser for syntax errors (unexpected token,
+/// unterminated parenthesis, unexpected end of input) and by `compile_jit`
+/// itself for semantic failures (unknown function, arity mismatch) — the
+/// latter don't yet narrow the span past the whole expression.
+#[derive(Debug, Clone)]
+struct CompileError {
+    span: (usize, usize),
+    message: String,
+}
+
+impl CompileError {
+    fn new(span: (usize, usize), message: impl Into<String>) -> Self {
+        CompileError {
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Render as a rustc/nac3-style "this flows here" snippet: the message,
+    /// the source line, and a caret underline beneath `self.span`.
+    fn render(&self, source: &str) -> String {
+        let (start, end) = self.span;
+        let start = start.min(source.len());
+        let end = end.max(start + 1).min(source.len().max(start + 1));
+        let underline_len = end.saturating_sub(start).max(1);
+        format!(
+            "{}\n    {}\n    {}{}",
+            self.message,
+            source,
+            " ".repeat(start),
+            "^".repeat(underline_len)
+        )
+    }
+}
+
+fn tokenize(expr: &str) -> Vec<Token> {
     let mut tokens = Vec::new();
     let mut cur = String::new();
-    for c in expr.chars() {
+    let mut cur_start = 0usize;
+    let mut chars = expr.char_indices().peekable();
+    while let Some(&(i, c)) = chars.peek() {
         if c.is_whitespace() {
             if !cur.is_empty() {
-                tokens.push(cur.clone());
+                tokens.push(Token { text: cur.clone(), span: (cur_start, i) });
                 cur.clear();
             }
-        } else if "+-*/()".contains(c) {
+            chars.next();
+        } else if "+-*/(),".contains(c) {
             if !cur.is_empty() {
-                tokens.push(cur.clone());
+                tokens.push(Token { text: cur.clone(), span: (cur_start, i) });
                 cur.clear();
             }
-            tokens.push(c.to_string());
+            tokens.push(Token { text: c.to_string(), span: (i, i + c.len_utf8()) });
+            chars.next();
+        } else if "<>=".contains(c) {
+            if !cur.is_empty() {
+                tokens.push(Token { text: cur.clone(), span: (cur_start, i) });
+                cur.clear();
+            }
+            chars.next();
+            let mut op = c.to_string();
+            let mut end = i + c.len_utf8();
+            if chars.peek().map(|&(_, c)| c) == Some('=') {
+                let (j, eq) = chars.next().unwrap();
+                op.push(eq);
+                end = j + eq.len_utf8();
+            }
+            tokens.push(Token { text: op, span: (i, end) });
         } else {
+            if cur.is_empty() {
+                cur_start = i;
+            }
             cur.push(c);
+            chars.next();
         }
     }
     if !cur.is_empty() {
-        tokens.push(cur);
+        tokens.push(Token { text: cur, span: (cur_start, expr.len()) });
     }
     tokens
 }
 
 // Pratt parser implementation
 struct Parser {
-    tokens: Vec<String>,
+    tokens: Vec<Token>,
     pos: usize,
+    /// Byte length of the source expression, used as the span for errors
+    /// raised once the token stream has been exhausted.
+    source_len: usize,
 }
 
 impl Parser {
-    fn new(tokens: Vec<String>) -> Self {
-        Parser { tokens, pos: 0 }
+    fn new(tokens: Vec<Token>, source_len: usize) -> Self {
+        Parser { tokens, pos: 0, source_len }
     }
 
     fn peek(&self) -> Option<&str> {
-        self.tokens.get(self.pos).map(|s| s.as_str())
+        self.tokens.get(self.pos).map(|t| t.text.as_str())
+    }
+
+    fn peek_span(&self) -> (usize, usize) {
+        self.tokens
+            .get(self.pos)
+            .map(|t| t.span)
+            .unwrap_or((self.source_len, self.source_len + 1))
     }
 
-    fn next(&mut self) -> Option<String> {
+    fn next(&mut self) -> Option<Token> {
         if self.pos < self.tokens.len() {
-            let s = self.tokens[self.pos].clone();
+            let t = self.tokens[self.pos].clone();
             self.pos += 1;
-            Some(s)
+            Some(t)
         } else {
             None
         }
     }
 
-    fn parse_expr(&mut self) -> Option<Expr> {
+    fn error(&self, message: impl Into<String>) -> CompileError {
+        CompileError::new(self.peek_span(), message.into())
+    }
+
+    /// Entry point. Precedence (low to high): ternary `if`/`else` < `or` <
+    /// `and` < comparisons < `+ -` < `* /` < parenthesized primary. There
+    /// are no unary operators (`-x` does not parse as a negation).
+    fn parse_expr(&mut self) -> Result<Expr, CompileError> {
+        self.parse_ternary()
+    }
+
+    fn parse_ternary(&mut self) -> Result<Expr, CompileError> {
+        let body = self.parse_or()?;
+        if self.peek() == Some("if") {
+            self.next();
+            let cond = self.parse_or()?;
+            if self.peek() != Some("else") {
+                return Err(self.error("expected 'else' to complete ternary expression"));
+            }
+            self.next();
+            let orelse = self.parse_ternary()?;
+            return Ok(Expr::IfExpr(Box::new(cond), Box::new(body), Box::new(orelse)));
+        }
+        Ok(body)
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, CompileError> {
+        let mut node = self.parse_and()?;
+        while self.peek() == Some("or") {
+            self.next();
+            let rhs = self.parse_and()?;
+            node = Expr::BoolOp(Box::new(node), BoolOpKind::Or, Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, CompileError> {
+        let mut node = self.parse_comparison()?;
+        while self.peek() == Some("and") {
+            self.next();
+            let rhs = self.parse_comparison()?;
+            node = Expr::BoolOp(Box::new(node), BoolOpKind::And, Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, CompileError> {
+        let mut node = self.parse_arith()?;
+        while let Some(op) = self.peek() {
+            let cmp = match op {
+                "<" => CompareOp::Lt,
+                "<=" => CompareOp::Le,
+                "==" => CompareOp::Eq,
+                ">" => CompareOp::Gt,
+                ">=" => CompareOp::Ge,
+                _ => break,
+            };
+            self.next();
+            let rhs = self.parse_arith()?;
+            node = Expr::Compare(Box::new(node), cmp, Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_arith(&mut self) -> Result<Expr, CompileError> {
         let mut node = self.parse_term()?;
         while let Some(op) = self.peek() {
             if op == "+" || op == "-" {
-                let op = self.next().unwrap().chars().next().unwrap();
+                let op = self.next().unwrap().text.chars().next().unwrap();
                 let rhs = self.parse_term()?;
                 node = Expr::BinOp(Box::new(node), op, Box::new(rhs));
                 continue;
             }
             break;
         }
-        Some(node)
+        Ok(node)
     }
 
-    fn parse_term(&mut self) -> Option<Expr> {
+    fn parse_term(&mut self) -> Result<Expr, CompileError> {
         let mut node = self.parse_factor()?;
         while let Some(op) = self.peek() {
             if op == "*" || op == "/" {
-                let op = self.next().unwrap().chars().next().unwrap();
+                let op = self.next().unwrap().text.chars().next().unwrap();
                 let rhs = self.parse_factor()?;
                 node = Expr::BinOp(Box::new(node), op, Box::new(rhs));
                 continue;
             }
             break;
         }
-        Some(node)
+        Ok(node)
     }
 
-    fn parse_factor(&mut self) -> Option<Expr> {
+    fn parse_factor(&mut self) -> Result<Expr, CompileError> {
         if let Some(tok) = self.peek() {
             if tok == "(" {
+                let open_span = self.peek_span();
                 self.next();
-                let expr = self.parse_expr();
+                let expr = self.parse_expr()?;
+                if self.peek() != Some(")") {
+                    return Err(CompileError::new(open_span, "unterminated parenthesis"));
+                }
                 self.next(); // consume ')'
-                return expr;
+                return Ok(expr);
             }
         }
         self.parse_primary()
     }
 
-    fn parse_primary(&mut self) -> Option<Expr> {
+    fn parse_primary(&mut self) -> Result<Expr, CompileError> {
         if let Some(tok) = self.next() {
-            if let Ok(num) = tok.parse::<f64>() {
-                return Some(Expr::Const(num));
+            // An integer literal (no '.', no exponent) gets its own node so
+            // type inference can keep whole arithmetic chains in `i64`
+            // instead of defaulting everything to `f64`.
+            if let Ok(num) = tok.text.parse::<i64>() {
+                return Ok(Expr::Int(num));
             }
-            // identifier
-            return Some(Expr::Var(tok));
+            if let Ok(num) = tok.text.parse::<f64>() {
+                return Ok(Expr::Const(num));
+            }
+            // An identifier immediately followed by '(' is a call;
+            // otherwise it's a variable reference.
+            if self.peek() == Some("(") {
+                let open_span = self.peek_span();
+                self.next();
+                let mut args = Vec::new();
+                if self.peek() != Some(")") {
+                    loop {
+                        args.push(self.parse_expr()?);
+                        if self.peek() == Some(",") {
+                            self.next();
+                            continue;
+                        }
+                        break;
+                    }
+                }
+                if self.peek() != Some(")") {
+                    return Err(CompileError::new(open_span, "unterminated call arguments"));
+                }
+                self.next(); // consume ')'
+                return Ok(Expr::Call(tok.text, args));
+            }
+            return Ok(Expr::Var(tok.text));
         }
-        None
+        Err(CompileError::new(
+            (self.source_len, self.source_len + 1),
+            "unexpected end of expression",
+        ))
+    }
+}
+
+// Transcendental math calls -------------------------------------------------
+//
+// `libm`'s functions use Rust's default calling convention, which isn't
+// guaranteed to match what cranelift's `call` instruction expects, so each
+// one gets an `extern "C"` shim whose address is what actually gets handed
+// to the JIT module.
+
+extern "C" fn libm_sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+extern "C" fn libm_cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+extern "C" fn libm_sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+extern "C" fn libm_exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+extern "C" fn libm_log(x: f64) -> f64 {
+    libm::log(x)
+}
+extern "C" fn libm_pow(x: f64, y: f64) -> f64 {
+    libm::pow(x, y)
+}
+
+/// Arity and native address of a JIT-callable math function, or `None` if
+/// `name` isn't one of the supported transcendentals.
+fn math_fn_ptr(name: &str) -> Option<(usize, *const u8)> {
+    match name {
+        "sin" => Some((1, libm_sin as *const u8)),
+        "cos" => Some((1, libm_cos as *const u8)),
+        "sqrt" => Some((1, libm_sqrt as *const u8)),
+        "exp" => Some((1, libm_exp as *const u8)),
+        "log" => Some((1, libm_log as *const u8)),
+        "pow" => Some((2, libm_pow as *const u8)),
+        _ => None,
+    }
+}
+
+/// `false` if the expression calls an unknown function or calls a known one
+/// with the wrong number of arguments — either way `compile_jit` bails out
+/// to let the caller fall back to the actor pool.
+fn validate_calls(expr: &Expr) -> bool {
+    match expr {
+        Expr::Const(_) | Expr::Int(_) | Expr::Var(_) => true,
+        Expr::BinOp(lhs, _, rhs) => validate_calls(lhs) && validate_calls(rhs),
+        Expr::Compare(lhs, _, rhs) => validate_calls(lhs) && validate_calls(rhs),
+        Expr::BoolOp(lhs, _, rhs) => validate_calls(lhs) && validate_calls(rhs),
+        Expr::IfExpr(cond, then_, else_) => {
+            validate_calls(cond) && validate_calls(then_) && validate_calls(else_)
+        }
+        Expr::Call(name, args) => {
+            math_fn_ptr(name).map(|(arity, _)| arity) == Some(args.len())
+                && args.iter().all(validate_calls)
+        }
+    }
+}
+
+/// Collect the distinct function names called anywhere in `expr`, so
+/// `compile_jit` only imports the symbols a given expression actually uses.
+fn collect_math_fns(expr: &Expr, out: &mut HashSet<String>) {
+    match expr {
+        Expr::Const(_) | Expr::Int(_) | Expr::Var(_) => {}
+        Expr::BinOp(lhs, _, rhs) | Expr::Compare(lhs, _, rhs) | Expr::BoolOp(lhs, _, rhs) => {
+            collect_math_fns(lhs, out);
+            collect_math_fns(rhs, out);
+        }
+        Expr::IfExpr(cond, then_, else_) => {
+            collect_math_fns(cond, out);
+            collect_math_fns(then_, out);
+            collect_math_fns(else_, out);
+        }
+        Expr::Call(name, args) => {
+            out.insert(name.clone());
+            for arg in args {
+                collect_math_fns(arg, out);
+            }
+        }
+    }
+}
+
+/// Unify two operand types the way arithmetic/conditional promotion does:
+/// `Bool` widens to `I64`, and either widens to `F64` once one side is
+/// already `F64`. Identical types are returned unchanged.
+fn unify(a: Ty, b: Ty) -> Ty {
+    if a == b {
+        return a;
+    }
+    match (a, b) {
+        (Ty::F64, _) | (_, Ty::F64) => Ty::F64,
+        (Ty::I64, _) | (_, Ty::I64) => Ty::I64,
+        _ => Ty::Bool,
+    }
+}
+
+/// Bottom-up unification pass. Returns the node's type once both of its
+/// children (if any) are resolved, and otherwise binds whichever `Var`
+/// leaves it can reach to the type its sibling already settled on. Run
+/// to a fixpoint by `compile_jit` so a type discovered deep in one branch
+/// has a chance to propagate out to an arg referenced elsewhere.
+fn infer_node(expr: &Expr, var_ty: &mut HashMap<String, Ty>) -> Option<Ty> {
+    fn bind(expr: &Expr, ty: Ty, var_ty: &mut HashMap<String, Ty>) {
+        if let Expr::Var(name) = expr {
+            var_ty.entry(name.clone()).or_insert(ty);
+        }
+    }
+
+    match expr {
+        Expr::Const(_) => Some(Ty::F64),
+        Expr::Int(_) => Some(Ty::I64),
+        Expr::Var(name) => var_ty.get(name).copied(),
+        Expr::BinOp(lhs, op, rhs) => {
+            let l = infer_node(lhs, var_ty);
+            let r = infer_node(rhs, var_ty);
+            if *op == '/' {
+                // division always yields float, regardless of operand types
+                return Some(Ty::F64);
+            }
+            match (l, r) {
+                (Some(lt), Some(rt)) => Some(unify(lt, rt)),
+                (Some(t), None) => {
+                    bind(rhs, t, var_ty);
+                    Some(t)
+                }
+                (None, Some(t)) => {
+                    bind(lhs, t, var_ty);
+                    Some(t)
+                }
+                (None, None) => None,
+            }
+        }
+        Expr::Compare(lhs, _, rhs) => {
+            let l = infer_node(lhs, var_ty);
+            let r = infer_node(rhs, var_ty);
+            match (l, r) {
+                (Some(t), None) => bind(rhs, t, var_ty),
+                (None, Some(t)) => bind(lhs, t, var_ty),
+                _ => {}
+            }
+            Some(Ty::Bool)
+        }
+        Expr::BoolOp(lhs, _, rhs) => {
+            infer_node(lhs, var_ty);
+            infer_node(rhs, var_ty);
+            Some(Ty::Bool)
+        }
+        Expr::IfExpr(cond, then_, else_) => {
+            infer_node(cond, var_ty);
+            let t = infer_node(then_, var_ty);
+            let e = infer_node(else_, var_ty);
+            match (t, e) {
+                (Some(tt), Some(et)) => Some(unify(tt, et)),
+                (Some(t), None) => {
+                    bind(else_, t, var_ty);
+                    Some(t)
+                }
+                (None, Some(t)) => {
+                    bind(then_, t, var_ty);
+                    Some(t)
+                }
+                (None, None) => None,
+            }
+        }
+        Expr::Call(_, args) => {
+            // Args are coerced to f64 at the call site regardless of their
+            // own inferred type, so a call imposes no constraint upward —
+            // just let its args keep propagating among themselves.
+            for arg in args {
+                infer_node(arg, var_ty);
+            }
+            Some(Ty::F64)
+        }
+    }
+}
+
+/// Final type of a node once `var_ty` has settled (all args bound, any
+/// leftover unconstrained arg defaulted to `F64` by the caller).
+fn node_ty(expr: &Expr, var_ty: &HashMap<String, Ty>) -> Ty {
+    match expr {
+        Expr::Const(_) => Ty::F64,
+        Expr::Int(_) => Ty::I64,
+        Expr::Var(name) => *var_ty.get(name).unwrap_or(&Ty::F64),
+        Expr::BinOp(lhs, op, rhs) => {
+            if *op == '/' {
+                Ty::F64
+            } else {
+                unify(node_ty(lhs, var_ty), node_ty(rhs, var_ty))
+            }
+        }
+        Expr::Compare(..) | Expr::BoolOp(..) => Ty::Bool,
+        Expr::IfExpr(_, then_, else_) => unify(node_ty(then_, var_ty), node_ty(else_, var_ty)),
+        Expr::Call(..) => Ty::F64,
+    }
+}
+
+// SIMD buffer-path kernel ----------------------------------------------------
+
+/// Widest f64 vector cranelift can lower on this host, and its lane count.
+/// Only AVX (256-bit, 4 lanes) vs. the SSE2 baseline (128-bit, 2 lanes) are
+/// distinguished — wider AVX-512 registers don't buy us anything here since
+/// we only ever fill a 4-lane `F64X4`.
+fn simd_lanes() -> (usize, types::Type) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx512f") || std::is_x86_feature_detected!("avx") {
+            return (4, types::F64X4);
+        }
+    }
+    (2, types::F64X2)
+}
+
+/// Whether `expr` is simple enough to lower to vector instructions: plain
+/// f64 arithmetic over a single buffer arg, no comparisons, booleans,
+/// conditionals or calls.
+fn is_vectorizable(expr: &Expr) -> bool {
+    match expr {
+        Expr::Const(_) | Expr::Var(_) => true,
+        Expr::BinOp(lhs, _, rhs) => is_vectorizable(lhs) && is_vectorizable(rhs),
+        Expr::Int(_) | Expr::Compare(..) | Expr::BoolOp(..) | Expr::IfExpr(..) | Expr::Call(..) => false,
+    }
+}
+
+/// Lane-wise counterpart of `gen_expr`'s arithmetic cases. `ptr` always
+/// points at the current group of `lanes` input elements (there's only one
+/// arg, so there's no per-arg offset to compute).
+fn gen_expr_vector(expr: &Expr, fb: &mut FunctionBuilder, ptr: Value, lane_ty: types::Type) -> Value {
+    match expr {
+        Expr::Const(n) => {
+            let scalar = fb.ins().f64const(*n);
+            fb.ins().splat(lane_ty, scalar)
+        }
+        Expr::Var(_) => fb.ins().load(lane_ty, MemFlags::new(), ptr, 0),
+        Expr::BinOp(lhs, op, rhs) => {
+            let l = gen_expr_vector(lhs, fb, ptr, lane_ty);
+            let r = gen_expr_vector(rhs, fb, ptr, lane_ty);
+            match op {
+                '+' => fb.ins().fadd(l, r),
+                '-' => fb.ins().fsub(l, r),
+                '*' => fb.ins().fmul(l, r),
+                '/' => fb.ins().fdiv(l, r),
+                _ => fb.ins().fadd(l, r),
+            }
+        }
+        _ => unreachable!("is_vectorizable rejects this node before gen_expr_vector is called"),
     }
 }
 
+/// Build the vector kernel as a second function in `module`: `fn(in_ptr:
+/// i64, out_ptr: i64)` that reads one `lanes`-wide vector from `in_ptr`,
+/// evaluates `expr` over it, and stores the result to `out_ptr`. Returns
+/// the declared `FuncId` (not yet a resolved address — the caller finalizes
+/// the module once, after both functions are defined) and the lane count.
+fn build_vector_kernel(module: &mut JITModule, expr: &Expr) -> Option<(cranelift_module::FuncId, usize)> {
+    let (lanes, lane_ty) = simd_lanes();
+
+    let mut ctx = module.make_context();
+    ctx.func.signature.params.push(AbiParam::new(types::I64));
+    ctx.func.signature.params.push(AbiParam::new(types::I64));
+
+    let mut func_ctx = FunctionBuilderContext::new();
+    {
+        let mut fb = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+        let entry = fb.create_block();
+        fb.append_block_params_for_function_params(entry);
+        fb.switch_to_block(entry);
+        fb.seal_block(entry);
+        let in_ptr = fb.block_params(entry)[0];
+        let out_ptr = fb.block_params(entry)[1];
+        let result = gen_expr_vector(expr, &mut fb, in_ptr, lane_ty);
+        fb.ins().store(MemFlags::new(), result, out_ptr, 0);
+        fb.ins().return_(&[]);
+        fb.finalize();
+    }
+
+    let id = module
+        .declare_function("jit_vec_func", Linkage::Local, &ctx.func.signature)
+        .ok()?;
+    module.define_function(id, &mut ctx).ok()?;
+    module.clear_context(&mut ctx);
+
+    Some((id, lanes))
+}
+
+/// Span covering the whole expression, used for diagnostics that aren't
+/// (yet) narrowed past "somewhere in this source" — semantic failures like
+/// an unknown function or a codegen-time symbol lookup miss, as opposed to
+/// the parser's precisely-spanned syntax errors.
+fn whole_span(expr_str: &str) -> (usize, usize) {
+    (0, expr_str.len())
+}
+
 // compile the simple expression to native code using cranelift
-fn compile_jit(expr_str: &str, arg_names: &[String]) -> Option<JitEntry> {
+fn compile_jit(expr_str: &str, arg_names: &[String]) -> Result<JitEntry, CompileError> {
     // tokenize and parse
     let tokens = tokenize(expr_str);
-    let mut parser = Parser::new(tokens);
+    let source_len = expr_str.len();
+    let mut parser = Parser::new(tokens, source_len);
     let expr = parser.parse_expr()?;
     let arg_count = arg_names.len();
 
+    // Bail out to the actor-pool fallback rather than emit a call to an
+    // undeclared or arity-mismatched symbol.
+    if !validate_calls(&expr) {
+        return Err(CompileError::new(
+            whole_span(expr_str),
+            "call to an unknown function or with the wrong number of arguments",
+        ));
+    }
+
+    // Unify argument/node types. One pass per arg is enough for a type to
+    // cross the whole tree: each pass can move a binding one hop further
+    // through the AST, and there are at most `arg_count` unbound args.
+    let mut var_ty: HashMap<String, Ty> = HashMap::new();
+    for _ in 0..=arg_count {
+        infer_node(&expr, &mut var_ty);
+    }
+    for name in arg_names {
+        var_ty.entry(name.clone()).or_insert(Ty::F64);
+    }
+    let ret_ty = node_ty(&expr, &var_ty);
+    let arg_types: Vec<Ty> = arg_names.iter().map(|n| var_ty[n]).collect();
+
+    let mut math_names = HashSet::new();
+    collect_math_fns(&expr, &mut math_names);
+
     // create a new JIT module for each compilation (avoids sync issues)
-    let builder = JITBuilder::new(cranelift_module::default_libcall_names()).expect("failed to create JITBuilder");
+    let mut builder = JITBuilder::new(cranelift_module::default_libcall_names()).expect("failed to create JITBuilder");
+    for name in &math_names {
+        let (_, ptr) = math_fn_ptr(name)
+            .ok_or_else(|| CompileError::new(whole_span(expr_str), format!("unknown math function '{name}'")))?;
+        builder.symbol(name.clone(), ptr);
+    }
     let mut module = JITModule::new(builder);
     let mut ctx = module.make_context();
     ctx.func.signature.params.push(AbiParam::new(types::I64));
-    ctx.func.signature.returns.push(AbiParam::new(types::F64));
+    // `Bool` is unified with `I64` at the ABI boundary: an 8-bit return
+    // register isn't guaranteed zero-extended by the callee, so we widen
+    // before `return_` below and declare the wider type here to match.
+    let abi_ret_ty = if ret_ty == Ty::Bool { types::I64 } else { ret_ty.cl_type() };
+    ctx.func.signature.returns.push(AbiParam::new(abi_ret_ty));
+
+    // Declare each called math function as an import with the right arity,
+    // so its `FuncRef` can be obtained inside the function body below.
+    let mut math_funcs = HashMap::new();
+    for name in &math_names {
+        let (arity, _) = math_fn_ptr(name)
+            .ok_or_else(|| CompileError::new(whole_span(expr_str), format!("unknown math function '{name}'")))?;
+        let mut sig = module.make_signature();
+        for _ in 0..arity {
+            sig.params.push(AbiParam::new(types::F64));
+        }
+        sig.returns.push(AbiParam::new(types::F64));
+        let id = module
+            .declare_function(name, Linkage::Import, &sig)
+            .map_err(|e| CompileError::new(whole_span(expr_str), format!("failed to declare '{name}': {e}")))?;
+        math_funcs.insert(name.clone(), id);
+    }
 
     let mut func_ctx = FunctionBuilderContext::new();
     {
         let mut fb = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+        let func_refs: HashMap<String, FuncRef> = math_funcs
+            .iter()
+            .map(|(name, id)| (name.clone(), module.declare_func_in_func(*id, fb.func)))
+            .collect();
         let entry = fb.create_block();
         fb.append_block_params_for_function_params(entry);
         fb.switch_to_block(entry);
         fb.seal_block(entry);
         let ptr_val = fb.block_params(entry)[0];
-        let val = gen_expr(&expr, &mut fb, ptr_val, arg_names);
+        let mut val = gen_expr(&expr, &mut fb, ptr_val, arg_names, &var_ty, &func_refs);
+        if ret_ty == Ty::Bool {
+            val = fb.ins().uextend(types::I64, val);
+        }
         fb.ins().return_(&[val]);
         fb.finalize();
     }
 
     let id = module
         .declare_function("jit_func", Linkage::Local, &ctx.func.signature)
-        .ok()?;
-    module.define_function(id, &mut ctx).ok()?;
+        .map_err(|e| CompileError::new(whole_span(expr_str), format!("failed to declare jit_func: {e}")))?;
+    module
+        .define_function(id, &mut ctx)
+        .map_err(|e| CompileError::new(whole_span(expr_str), format!("failed to define jit_func: {e}")))?;
     module.clear_context(&mut ctx);
+
+    let vector_id = if arg_count == 1 && arg_types[0] == Ty::F64 && ret_ty == Ty::F64 && is_vectorizable(&expr) {
+        build_vector_kernel(&mut module, &expr)
+    } else {
+        None
+    };
+
     module.finalize_definitions();
 
     let code_ptr = module.get_finalized_function(id) as usize;
-    Some(JitEntry {
+    let vector_entry = vector_id.map(|(vid, lanes)| (module.get_finalized_function(vid) as usize, lanes));
+    Ok(JitEntry {
         func_ptr: code_ptr,
         arg_count,
+        arg_types,
+        ret_type: ret_ty,
+        vector_entry,
+        target: JitTarget::Host,
+        opt_level: "none".to_owned(),
     })
 }
 
+// AOT object compilation + on-disk cache -------------------------------------
+//
+// `compile_jit` above always builds a throwaway in-process `JITModule`, so
+// warm-starting a process re-JITs every decorated function from scratch and
+// can only ever target this host. The functions below give `register_offload`
+// an opt-in path that drives `cranelift-object`'s `ObjectModule` from an
+// explicitly-built `isa` instead, and caches the resulting object (plus the
+// type metadata needed to reconstruct a `JitEntry`) under a cache directory
+// keyed by a hash of `(source_expr, arg_names, target, opt_level)`.
+
+/// Directory AOT artifacts are cached under. Overridable for tests/tooling
+/// via `IRIS_JIT_CACHE_DIR`; otherwise a subdirectory of the system temp dir.
+fn cache_dir() -> PathBuf {
+    std::env::var_os("IRIS_JIT_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::temp_dir().join("iris-jit-cache"))
+}
+
+/// Hash the inputs that fully determine a compiled artifact. Any change to
+/// `source_expr`, `arg_names`, `target`, or `opt_level` must produce a
+/// different key so a stale cache entry is never reused.
+fn cache_key(source_expr: &str, arg_names: &[String], target: JitTarget, opt_level: &str) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    source_expr.hash(&mut hasher);
+    arg_names.hash(&mut hasher);
+    target.hash(&mut hasher);
+    opt_level.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+fn ty_tag(ty: Ty) -> &'static str {
+    match ty {
+        Ty::I64 => "i64",
+        Ty::F64 => "f64",
+        Ty::Bool => "bool",
+    }
+}
+
+fn parse_ty_tag(s: &str) -> Option<Ty> {
+    match s {
+        "i64" => Some(Ty::I64),
+        "f64" => Some(Ty::F64),
+        "bool" => Some(Ty::Bool),
+        _ => None,
+    }
+}
+
+/// Sidecar metadata next to a cached `.o`/`.so`: cranelift's object output
+/// carries no record of the source-level arg/return types `JitEntry` needs,
+/// so we write our own one-tag-per-line file alongside it.
+fn write_cache_meta(path: &Path, arg_types: &[Ty], ret_type: Ty) -> std::io::Result<()> {
+    let mut contents = String::new();
+    contents.push_str(ty_tag(ret_type));
+    contents.push('\n');
+    for ty in arg_types {
+        contents.push_str(ty_tag(*ty));
+        contents.push('\n');
+    }
+    std::fs::write(path, contents)
+}
+
+fn read_cache_meta(path: &Path) -> Option<(Vec<Ty>, Ty)> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    let mut lines = contents.lines();
+    let ret_type = parse_ty_tag(lines.next()?)?;
+    let arg_types = lines.map(parse_ty_tag).collect::<Option<Vec<_>>>()?;
+    Some((arg_types, ret_type))
+}
+
+/// Link a compiled `.o` into a `.so` we can `dlopen`. Cranelift's object
+/// output is a relocatable ELF/Mach-O object, not a final shared library, so
+/// turning it into something loadable still needs a real linker.
+fn link_shared_object(obj_path: &Path, so_path: &Path) -> std::io::Result<()> {
+    let status = std::process::Command::new("cc")
+        .arg("-shared")
+        .arg("-o")
+        .arg(so_path)
+        .arg(obj_path)
+        .status()?;
+    if !status.success() {
+        return Err(std::io::Error::new(std::io::ErrorKind::Other, "cc -shared failed"));
+    }
+    Ok(())
+}
+
+/// `dlopen` a cached host `.so` and resolve its `jit_func` export into a
+/// `JitEntry`. The library is deliberately leaked (`mem::forget`): the
+/// returned function pointer is kept alive in `JIT_REGISTRY` for the rest of
+/// the process, so the mapping backing it must never be unmapped.
+fn load_cached_host_entry(so_path: &Path, arg_types: Vec<Ty>, ret_type: Ty, opt_level: &str) -> Option<JitEntry> {
+    let lib = unsafe { libloading::Library::new(so_path) }.ok()?;
+    let sym: libloading::Symbol<unsafe extern "C" fn()> = unsafe { lib.get(b"jit_func\0") }.ok()?;
+    let func_ptr = *sym as usize;
+    std::mem::forget(lib);
+    Some(JitEntry {
+        func_ptr,
+        arg_count: arg_types.len(),
+        arg_types,
+        ret_type,
+        vector_entry: None,
+        target: JitTarget::Host,
+        opt_level: opt_level.to_owned(),
+    })
+}
+
+/// Compile `expr_str` to a relocatable object via `ObjectModule`, returning
+/// its bytes plus the type metadata a `JitEntry` needs. Shares the
+/// tokenize/parse/infer/codegen pipeline with `compile_jit`; only the module
+/// backend (`ObjectModule` driven by an explicit `isa` instead of the
+/// host-only `JITBuilder`) differs.
+///
+/// Math calls (`sin`, `sqrt`, ...) are declared as imports the same way the
+/// in-process path declares them, but an object file can't bind an import to
+/// an in-process function pointer — it can only reference the symbol by
+/// name and leave it for the linker to resolve. We don't yet ship a way for
+/// `link_shared_object` to supply `libm_sin` et al. to that link step, so
+/// AOT compilation is restricted to expressions with no math calls until
+/// that's wired up.
+fn compile_jit_object(
+    expr_str: &str,
+    arg_names: &[String],
+    target: JitTarget,
+    opt_level: &str,
+) -> Option<(Vec<u8>, Vec<Ty>, Ty)> {
+    let tokens = tokenize(expr_str);
+    let mut parser = Parser::new(tokens, expr_str.len());
+    // The AOT path reports a parse failure only as `None`, without the span
+    // the in-process `compile_jit` carries: `register_offload` then raises a
+    // plain `IrisJitError` under `strict_jit`, or logs and falls back.
+    let expr = parser.parse_expr().ok()?;
+    let arg_count = arg_names.len();
+
+    if !validate_calls(&expr) {
+        return None;
+    }
+
+    let mut math_names = HashSet::new();
+    collect_math_fns(&expr, &mut math_names);
+    if !math_names.is_empty() {
+        eprintln!("[Iris][jit] AOT path does not yet support math calls, skipping cache");
+        return None;
+    }
+
+    let mut var_ty: HashMap<String, Ty> = HashMap::new();
+    for _ in 0..=arg_count {
+        infer_node(&expr, &mut var_ty);
+    }
+    for name in arg_names {
+        var_ty.entry(name.clone()).or_insert(Ty::F64);
+    }
+    let ret_ty = node_ty(&expr, &var_ty);
+    let arg_types: Vec<Ty> = arg_names.iter().map(|n| var_ty[n]).collect();
+
+    let mut flag_builder = cranelift_codegen::settings::builder();
+    flag_builder.set("opt_level", opt_level).ok()?;
+    let isa_builder = cranelift_codegen::isa::lookup(target.triple()).ok()?;
+    let isa = isa_builder
+        .finish(cranelift_codegen::settings::Flags::new(flag_builder))
+        .ok()?;
+
+    let obj_builder =
+        cranelift_object::ObjectBuilder::new(isa, "iris_jit", cranelift_module::default_libcall_names()).ok()?;
+    let mut module = cranelift_object::ObjectModule::new(obj_builder);
+    let mut ctx = module.make_context();
+    ctx.func.signature.params.push(AbiParam::new(types::I64));
+    let abi_ret_ty = if ret_ty == Ty::Bool { types::I64 } else { ret_ty.cl_type() };
+    ctx.func.signature.returns.push(AbiParam::new(abi_ret_ty));
+
+    let mut func_ctx = FunctionBuilderContext::new();
+    {
+        let mut fb = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+        let func_refs: HashMap<String, FuncRef> = HashMap::new();
+        let entry = fb.create_block();
+        fb.append_block_params_for_function_params(entry);
+        fb.switch_to_block(entry);
+        fb.seal_block(entry);
+        let ptr_val = fb.block_params(entry)[0];
+        let mut val = gen_expr(&expr, &mut fb, ptr_val, arg_names, &var_ty, &func_refs);
+        if ret_ty == Ty::Bool {
+            val = fb.ins().uextend(types::I64, val);
+        }
+        fb.ins().return_(&[val]);
+        fb.finalize();
+    }
+
+    let id = module
+        .declare_function("jit_func", Linkage::Export, &ctx.func.signature)
+        .ok()?;
+    module.define_function(id, &mut ctx).ok()?;
+    module.clear_context(&mut ctx);
+
+    let product = module.finish();
+    let bytes = product.emit().ok()?;
+    Some((bytes, arg_types, ret_ty))
+}
+
+/// Entry point for `register_offload`'s AOT path: check the on-disk cache
+/// keyed by `(expr_str, arg_names, target, opt_level)` before recompiling,
+/// and write through to it on a miss. Host hits return a ready-to-call
+/// `JitEntry`; cross-target runs only ever populate the cache, since their
+/// object code can't run on this process.
+fn compile_jit_cached(expr_str: &str, arg_names: &[String], target: JitTarget, opt_level: &str) -> Option<JitEntry> {
+    let key = cache_key(expr_str, arg_names, target, opt_level);
+    let dir = cache_dir();
+    if std::fs::create_dir_all(&dir).is_err() {
+        eprintln!("[Iris][jit] could not create AOT cache dir {:?}", dir);
+    }
+    let obj_path = dir.join(format!("{key}.o"));
+    let meta_path = dir.join(format!("{key}.meta"));
+    let so_path = dir.join(format!("{key}.so"));
+
+    if target.is_host() && so_path.exists() {
+        if let Some((arg_types, ret_type)) = read_cache_meta(&meta_path) {
+            if let Some(entry) = load_cached_host_entry(&so_path, arg_types, ret_type, opt_level) {
+                eprintln!("[Iris][jit] AOT cache hit key={}", key);
+                return Some(entry);
+            }
+        }
+    }
+
+    let (obj_bytes, arg_types, ret_type) = compile_jit_object(expr_str, arg_names, target, opt_level)?;
+    if let Err(e) = std::fs::write(&obj_path, &obj_bytes) {
+        eprintln!("[Iris][jit] failed to write AOT object {:?}: {}", obj_path, e);
+        return None;
+    }
+    let _ = write_cache_meta(&meta_path, &arg_types, ret_type);
+
+    if !target.is_host() {
+        eprintln!(
+            "[Iris][jit] emitted cross-target ({}) object to {:?}; not loaded into this process",
+            target, obj_path
+        );
+        return None;
+    }
+
+    if let Err(e) = link_shared_object(&obj_path, &so_path) {
+        eprintln!("[Iris][jit] AOT link failed for key={}: {}", key, e);
+        return None;
+    }
+    load_cached_host_entry(&so_path, arg_types, ret_type, opt_level)
+}
+
+/// Convert `val` (of type `from`) up to `to`. Only ever widens (`Bool` ->
+/// `I64` -> `F64`), since `unify`/`node_ty` never produce a narrowing
+/// target.
+fn coerce(fb: &mut FunctionBuilder, val: Value, from: Ty, to: Ty) -> Value {
+    if from == to {
+        return val;
+    }
+    match (from, to) {
+        (Ty::Bool, Ty::I64) => fb.ins().uextend(types::I64, val),
+        (Ty::Bool, Ty::F64) => {
+            let widened = fb.ins().uextend(types::I64, val);
+            fb.ins().fcvt_from_sint(types::F64, widened)
+        }
+        (Ty::I64, Ty::F64) => fb.ins().fcvt_from_sint(types::F64, val),
+        _ => val,
+    }
+}
+
 fn gen_expr(
     expr: &Expr,
     fb: &mut FunctionBuilder,
     ptr: Value,
     arg_names: &[String],
+    var_ty: &HashMap<String, Ty>,
+    funcs: &HashMap<String, FuncRef>,
 ) -> Value {
     match expr {
         Expr::Const(n) => fb.ins().f64const(*n),
+        Expr::Int(n) => fb.ins().iconst(types::I64, *n),
         Expr::Var(name) => {
             let idx = arg_names.iter().position(|n| n == name).unwrap_or(0);
+            let ty = *var_ty.get(name).unwrap_or(&Ty::F64);
             let offset = (idx as i64) * 8;
             let offset_const = fb.ins().iconst(types::I64, offset);
             let addr1 = fb.ins().iadd(ptr, offset_const);
-            fb.ins().load(types::F64, MemFlags::new(), addr1, 0)
+            fb.ins().load(ty.cl_type(), MemFlags::new(), addr1, 0)
         }
         Expr::BinOp(lhs, op, rhs) => {
-            let l = gen_expr(lhs, fb, ptr, arg_names);
-            let r = gen_expr(rhs, fb, ptr, arg_names);
-            match op {
-                '+' => fb.ins().fadd(l, r),
-                '-' => fb.ins().fsub(l, r),
-                '*' => fb.ins().fmul(l, r),
-                '/' => fb.ins().fdiv(l, r),
+            let lt = node_ty(lhs, var_ty);
+            let rt = node_ty(rhs, var_ty);
+            let result_ty = if *op == '/' { Ty::F64 } else { unify(lt, rt) };
+            let l = gen_expr(lhs, fb, ptr, arg_names, var_ty, funcs);
+            let r = gen_expr(rhs, fb, ptr, arg_names, var_ty, funcs);
+            let l = coerce(fb, l, lt, result_ty);
+            let r = coerce(fb, r, rt, result_ty);
+            match (op, result_ty) {
+                ('+', Ty::F64) => fb.ins().fadd(l, r),
+                ('+', _) => fb.ins().iadd(l, r),
+                ('-', Ty::F64) => fb.ins().fsub(l, r),
+                ('-', _) => fb.ins().isub(l, r),
+                ('*', Ty::F64) => fb.ins().fmul(l, r),
+                ('*', _) => fb.ins().imul(l, r),
+                ('/', _) => fb.ins().fdiv(l, r),
                 _ => fb.ins().fadd(l, r),
             }
         }
+        Expr::Compare(lhs, cmp, rhs) => {
+            let lt = node_ty(lhs, var_ty);
+            let rt = node_ty(rhs, var_ty);
+            let common = unify(lt, rt);
+            let l = gen_expr(lhs, fb, ptr, arg_names, var_ty, funcs);
+            let r = gen_expr(rhs, fb, ptr, arg_names, var_ty, funcs);
+            let l = coerce(fb, l, lt, common);
+            let r = coerce(fb, r, rt, common);
+            if common == Ty::F64 {
+                let cc = match cmp {
+                    CompareOp::Lt => FloatCC::LessThan,
+                    CompareOp::Le => FloatCC::LessThanOrEqual,
+                    CompareOp::Eq => FloatCC::Equal,
+                    CompareOp::Gt => FloatCC::GreaterThan,
+                    CompareOp::Ge => FloatCC::GreaterThanOrEqual,
+                };
+                fb.ins().fcmp(cc, l, r)
+            } else {
+                let cc = match cmp {
+                    CompareOp::Lt => IntCC::SignedLessThan,
+                    CompareOp::Le => IntCC::SignedLessThanOrEqual,
+                    CompareOp::Eq => IntCC::Equal,
+                    CompareOp::Gt => IntCC::SignedGreaterThan,
+                    CompareOp::Ge => IntCC::SignedGreaterThanOrEqual,
+                };
+                fb.ins().icmp(cc, l, r)
+            }
+        }
+        Expr::BoolOp(lhs, kind, rhs) => {
+            // Operands are already `Bool` (i8 0/1) by construction, so
+            // bitwise and/or double as short-circuit-free logical ops.
+            let l = gen_expr(lhs, fb, ptr, arg_names, var_ty, funcs);
+            let r = gen_expr(rhs, fb, ptr, arg_names, var_ty, funcs);
+            match kind {
+                BoolOpKind::And => fb.ins().band(l, r),
+                BoolOpKind::Or => fb.ins().bor(l, r),
+            }
+        }
+        Expr::IfExpr(cond, then_, else_) => {
+            let cond_ty = node_ty(cond, var_ty);
+            let raw_cond = gen_expr(cond, fb, ptr, arg_names, var_ty, funcs);
+            // `if`/`else` follows Python truthiness: a bare numeric cond
+            // is compared against zero rather than requiring an explicit
+            // comparison expression.
+            let c = match cond_ty {
+                Ty::Bool => raw_cond,
+                Ty::I64 => {
+                    let zero = fb.ins().iconst(types::I64, 0);
+                    fb.ins().icmp(IntCC::NotEqual, raw_cond, zero)
+                }
+                Ty::F64 => {
+                    let zero = fb.ins().f64const(0.0);
+                    fb.ins().fcmp(FloatCC::NotEqual, raw_cond, zero)
+                }
+            };
+            let result_ty = unify(node_ty(then_, var_ty), node_ty(else_, var_ty));
+            let t = gen_expr(then_, fb, ptr, arg_names, var_ty, funcs);
+            let e = gen_expr(else_, fb, ptr, arg_names, var_ty, funcs);
+            let t = coerce(fb, t, node_ty(then_, var_ty), result_ty);
+            let e = coerce(fb, e, node_ty(else_, var_ty), result_ty);
+            fb.ins().select(c, t, e)
+        }
+        Expr::Call(name, args) => {
+            // Every known math function takes and returns f64, so coerce
+            // whatever type each arg inferred to before the call.
+            let arg_vals: Vec<Value> = args
+                .iter()
+                .map(|a| {
+                    let v = gen_expr(a, fb, ptr, arg_names, var_ty, funcs);
+                    coerce(fb, v, node_ty(a, var_ty), Ty::F64)
+                })
+                .collect();
+            let fref = funcs[name];
+            let call = fb.ins().call(fref, &arg_vals);
+            fb.inst_results(call)[0]
+        }
     }
 }
 
 // Python bindings -----------------------------------------------------------
 
+/// Raised when `strategy="jit"` compilation fails and `strict_jit=True` was
+/// passed to `register_offload`. Carries a rustc/nac3-style snippet (the
+/// source expression with a `^^^` underline beneath the offending span) as
+/// its message, so the traceback alone is enough to find the problem.
+#[cfg(feature = "pyo3")]
+pyo3::create_exception!(
+    iris,
+    IrisJitError,
+    pyo3::exceptions::PyException,
+    "A `strategy=\"jit\"` offload target failed to compile under `strict_jit=True`."
+);
+
 /// Initialize the Python submodule (called from `wrappers.populate_module`).
 #[cfg(feature = "pyo3")]
 pub(crate) fn init_py(m: &PyModule) -> PyResult<()> {
     m.add_function(pyo3::wrap_pyfunction!(register_offload, m)?)?;
     m.add_function(pyo3::wrap_pyfunction!(offload_call, m)?)?;
     m.add_function(pyo3::wrap_pyfunction!(call_jit, m)?)?;
+    m.add("IrisJitError", m.py().get_type::<IrisJitError>())?;
     Ok(())
 }
 
@@ -296,34 +1428,92 @@ pub(crate) fn init_py(m: &PyModule) -> PyResult<()> {
 /// This is the Rust-side hook invoked by the decorator.  For now we simply
 /// return the original callable back to Python unmodified, but we log for
 /// inspection and ensure the actor pool is initialized when strategy=actor.
+///
+/// `target`/`cache` opt a `strategy="jit"` registration into the AOT
+/// object-file path (`compile_jit_cached`) instead of the default
+/// throwaway in-process `JITModule`: a warm-start cache for `target="host"`
+/// (or when `target` is omitted but `cache` is requested), and reusable
+/// cross-target object code for anything else. `opt_level` applies only on
+/// that path (default `"speed"`). When neither is set the
+/// existing in-process `compile_jit` path is used, and a compile failure
+/// there either raises `IrisJitError` (`strict_jit=True`) or falls back to
+/// the actor pool the way it always has (`strict_jit=False`, the default).
 #[cfg(feature = "pyo3")]
 #[pyfunction]
 fn register_offload(
+    py: Python,
     func: PyObject,
     strategy: Option<String>,
     return_type: Option<String>,
     source_expr: Option<String>,
     arg_names: Option<Vec<String>>,
+    target: Option<String>,
+    opt_level: Option<String>,
+    cache: Option<bool>,
+    strict_jit: Option<bool>,
 ) -> PyResult<PyObject> {
+    // Interning gives us a stable key for this callable that survives
+    // GC-driven address reuse, unlike `func.as_ptr() as usize`.
+    let key = embedding_map().lock().unwrap().intern_function(py, func.as_ref(py));
     if let Some(ref s) = strategy {
         if s == "actor" {
             let _ = get_offload_pool();
         } else if s == "jit" {
             if let (Some(expr), Some(args)) = (source_expr.clone(), arg_names.clone()) {
-                if let Some(entry) = compile_jit(&expr, &args) {
-                    // store compiled entry keyed by python function pointer
-                    let key = func.as_ptr() as usize;
-                    register_jit(key, entry);
-                    eprintln!("[Iris][jit] compiled JIT for function ptr={}", key);
+                let use_aot = target.is_some() || cache.unwrap_or(false);
+                if use_aot {
+                    let jit_target = target
+                        .as_deref()
+                        .and_then(JitTarget::parse)
+                        .unwrap_or(JitTarget::Host);
+                    let level = opt_level.as_deref().unwrap_or("speed");
+                    match compile_jit_cached(&expr, &args, jit_target, level) {
+                        Some(entry) => {
+                            register_jit(key, entry);
+                            eprintln!("[Iris][jit] compiled AOT entry for function key={}", key);
+                        }
+                        // A non-host target intentionally returns `None`
+                        // here: the object file was cross-compiled and
+                        // written to the cache dir (compile_jit_cached
+                        // already logged where), it's just not something
+                        // this process can load and run. That's not a
+                        // failure, so don't report it as one.
+                        None if !jit_target.is_host() => {}
+                        None => {
+                            if strict_jit.unwrap_or(false) {
+                                return Err(IrisJitError::new_err(format!(
+                                    "AOT compile failed for expr: {}",
+                                    expr
+                                )));
+                            }
+                            eprintln!("[Iris][jit] failed to compile (AOT) expr: {}", expr);
+                        }
+                    }
                 } else {
-                    eprintln!("[Iris][jit] failed to compile expr: {}", expr);
+                    match compile_jit(&expr, &args) {
+                        Ok(entry) => {
+                            register_jit(key, entry);
+                            let expr_key = embedding_map().lock().unwrap().intern_string(&expr);
+                            eprintln!(
+                                "[Iris][jit] compiled JIT for function key={} (expr key={})",
+                                key, expr_key
+                            );
+                        }
+                        Err(compile_err) => {
+                            let snippet = compile_err.render(&expr);
+                            if strict_jit.unwrap_or(false) {
+                                return Err(IrisJitError::new_err(snippet));
+                            }
+                            eprintln!("[Iris][jit] failed to compile expr, falling back to actor pool:\n{}", snippet);
+                        }
+                    }
                 }
             }
         }
     }
     eprintln!(
-        "[Iris][jit] register_offload called strategy={:?} return_type={:?} source={:?} args={:?}",
-        strategy, return_type, source_expr, arg_names
+        "[Iris][jit] register_offload called strategy={:?} return_type={:?} source={:?} args={:?} target={:?} opt_level={:?}",
+        strategy, return_type, source_expr, arg_names, target, opt_level
     );
     Ok(func)
 }
@@ -349,45 +1539,97 @@ unsafe fn buffer_ptr_len(obj: &PyAny) -> Option<(*const f64, usize)> {
     Some((ptr, len))
 }
 
-/// Highly optimized helper to execute a JIT compiled function. 
+/// Read one Python scalar into the raw 8-byte slot `gen_expr` loads from,
+/// using the inferred type to pick `PyLong_AsLongLong` vs `PyFloat_AsDouble`.
+/// `Bool` args are unpacked as ints for the same reason they're boxed as
+/// ints: Python `bool` already is one.
+unsafe fn pack_scalar_arg(py: Python, item: *mut pyo3::ffi::PyObject, ty: Ty) -> PyResult<u64> {
+    match ty {
+        Ty::F64 => {
+            let v = pyo3::ffi::PyFloat_AsDouble(item);
+            if v == -1.0 && !pyo3::ffi::PyErr_Occurred().is_null() {
+                return Err(PyErr::fetch(py));
+            }
+            Ok(v.to_bits())
+        }
+        Ty::I64 | Ty::Bool => {
+            let v = pyo3::ffi::PyLong_AsLongLong(item);
+            if v == -1 && !pyo3::ffi::PyErr_Occurred().is_null() {
+                return Err(PyErr::fetch(py));
+            }
+            Ok(v as u64)
+        }
+    }
+}
+
+/// Box the raw return slot according to the compiled function's inferred
+/// return type. `Bool` is boxed as a Python `int` (see `pack_scalar_arg`).
+fn box_return(py: Python, entry: &JitEntry, stack: *const u64) -> PyObject {
+    match entry.ret_type {
+        Ty::F64 => {
+            let f: extern "C" fn(*const u64) -> f64 = unsafe { std::mem::transmute(entry.func_ptr) };
+            f(stack).into_py(py)
+        }
+        Ty::I64 | Ty::Bool => {
+            let f: extern "C" fn(*const u64) -> i64 = unsafe { std::mem::transmute(entry.func_ptr) };
+            f(stack).into_py(py)
+        }
+    }
+}
+
+/// Highly optimized helper to execute a JIT compiled function.
 /// Handles zero-copy buffers (including vectorization) and scalar argument unpacking via stack.
 #[cfg(feature = "pyo3")]
 #[inline(always)]
 fn execute_jit_func(py: Python, entry: &JitEntry, args: &PyTuple) -> PyResult<PyObject> {
     let arg_count = args.len();
 
-    // Try zero-copy buffer path first
-    if arg_count == 1 {
+    // Try zero-copy buffer path first. Only sound when the single arg and
+    // the return value are both `f64`: the buffer is read/written as raw
+    // f64 elements with no per-element type dispatch.
+    if arg_count == 1
+        && entry.arg_count == 1
+        && entry.arg_types.first() == Some(&Ty::F64)
+        && entry.ret_type == Ty::F64
+    {
         if let Ok(item) = args.get_item(0) {
             if let Some((ptr, len)) = unsafe { buffer_ptr_len(item) } {
                 let f: extern "C" fn(*const f64) -> f64 = unsafe { std::mem::transmute(entry.func_ptr) };
-                
-                // Vectorization path: Apply a 1-argument function across the entire buffer internally
-                if entry.arg_count == 1 {
-                    let mut results = Vec::with_capacity(len);
-                    for i in 0..len {
-                        let res = f(unsafe { ptr.add(i) });
-                        results.push(res);
+                let mut results = vec![0.0f64; len];
+
+                // Vectorized path: process whole lane-groups with the SIMD
+                // kernel, then fall back to the scalar entry point for
+                // whatever doesn't fill a full vector.
+                let tail_start = match entry.vector_entry {
+                    Some((vec_func_ptr, lanes)) if len >= lanes => {
+                        let vf: extern "C" fn(*const f64, *mut f64) = unsafe { std::mem::transmute(vec_func_ptr) };
+                        let full_vectors = len / lanes;
+                        for v in 0..full_vectors {
+                            let offset = v * lanes;
+                            vf(unsafe { ptr.add(offset) }, unsafe {
+                                results.as_mut_ptr().add(offset)
+                            });
+                        }
+                        full_vectors * lanes
                     }
-                    
-                    // Zero-copy output: construct a Python array.array directly from our memory bytes
-                    let byte_slice = unsafe {
-                        std::slice::from_raw_parts(
-                            results.as_ptr() as *const u8,
-                            results.len() * std::mem::size_of::<f64>(),
-                        )
-                    };
-                    let py_bytes = PyBytes::new(py, byte_slice);
-                    let array_mod = py.import("array")?;
-                    let array_obj = array_mod.getattr("array")?.call1(("d", py_bytes))?;
-                    
-                    return Ok(array_obj.into_py(py));
-                } 
-                // Fallback: The buffer itself represents a single set of arguments
-                else if len == entry.arg_count {
-                    let res = f(ptr);
-                    return Ok(res.into_py(py));
+                    _ => 0,
+                };
+                for i in tail_start..len {
+                    results[i] = f(unsafe { ptr.add(i) });
                 }
+
+                // Zero-copy output: construct a Python array.array directly from our memory bytes
+                let byte_slice = unsafe {
+                    std::slice::from_raw_parts(
+                        results.as_ptr() as *const u8,
+                        results.len() * std::mem::size_of::<f64>(),
+                    )
+                };
+                let py_bytes = PyBytes::new(py, byte_slice);
+                let array_mod = py.import("array")?;
+                let array_obj = array_mod.getattr("array")?.call1(("d", py_bytes))?;
+
+                return Ok(array_obj.into_py(py));
             }
         }
     }
@@ -401,34 +1643,24 @@ fn execute_jit_func(py: Python, entry: &JitEntry, args: &PyTuple) -> PyResult<Py
     // Fast path for small number of scalar arguments (stack allocated array)
     const MAX_FAST_ARGS: usize = 8;
     if arg_count <= MAX_FAST_ARGS {
-        let mut stack_args: [f64; MAX_FAST_ARGS] = [0.0; MAX_FAST_ARGS];
+        let mut stack_args: [u64; MAX_FAST_ARGS] = [0; MAX_FAST_ARGS];
         for i in 0..arg_count {
             let item = unsafe { pyo3::ffi::PyTuple_GET_ITEM(args.as_ptr(), i as isize) };
-            let val = unsafe { pyo3::ffi::PyFloat_AsDouble(item) };
-            if val == -1.0 && !unsafe { pyo3::ffi::PyErr_Occurred() }.is_null() {
-                return Err(PyErr::fetch(py));
-            }
-            stack_args[i] = val;
+            let ty = entry.arg_types.get(i).copied().unwrap_or(Ty::F64);
+            stack_args[i] = unsafe { pack_scalar_arg(py, item, ty) }?;
         }
-        
-        let f: extern "C" fn(*const f64) -> f64 = unsafe { std::mem::transmute(entry.func_ptr) };
-        let res = f(stack_args.as_ptr());
-        return Ok(res.into_py(py));
+
+        return Ok(box_return(py, entry, stack_args.as_ptr()));
     }
 
     // Fallback for > 8 args: heap allocation
     let mut heap_args = Vec::with_capacity(arg_count);
     for i in 0..arg_count {
         let item = unsafe { pyo3::ffi::PyTuple_GET_ITEM(args.as_ptr(), i as isize) };
-        let val = unsafe { pyo3::ffi::PyFloat_AsDouble(item) };
-        if val == -1.0 && !unsafe { pyo3::ffi::PyErr_Occurred() }.is_null() {
-            return Err(PyErr::fetch(py));
-        }
-        heap_args.push(val);
+        let ty = entry.arg_types.get(i).copied().unwrap_or(Ty::F64);
+        heap_args.push(unsafe { pack_scalar_arg(py, item, ty) }?);
     }
-    let f: extern "C" fn(*const f64) -> f64 = unsafe { std::mem::transmute(entry.func_ptr) };
-    let res = f(heap_args.as_ptr());
-    Ok(res.into_py(py))
+    Ok(box_return(py, entry, heap_args.as_ptr()))
 }
 
 /// Execute a Python callable on the offload actor pool, blocking until result.
@@ -440,7 +1672,7 @@ fn offload_call(
     args: &PyTuple,
     kwargs: Option<&PyDict>,
 ) -> PyResult<PyObject> {
-    let key = func.as_ptr() as usize;
+    let key = embedding_map().lock().unwrap().intern_function(py, func.as_ref(py));
     if let Some(entry) = lookup_jit(key) {
         if let Ok(res) = execute_jit_func(py, &entry, args) {
             return Ok(res);
@@ -451,7 +1683,7 @@ fn offload_call(
 
     let (tx, rx) = std::sync::mpsc::channel();
     let task = OffloadTask {
-        func: func.into_py(py),
+        func_key: key,
         args: args.into_py(py),
         kwargs: kwargs.map(|d: &PyDict| d.into_py(py)),
         resp: tx,
@@ -483,7 +1715,7 @@ fn call_jit(
     args: &PyTuple,
     _kwargs: Option<&PyDict>,
 ) -> PyResult<PyObject> {
-    let key = func.as_ptr() as usize;
+    let key = embedding_map().lock().unwrap().intern_function(py, func.as_ref(py));
     if let Some(entry) = lookup_jit(key) {
         return execute_jit_func(py, &entry, args);
     }
@@ -1,10 +1,13 @@
 // src/py/mailbox.rs
-//! Python mailbox wrapper providing blocking recv/selective_recv.
+//! Python mailbox wrapper providing blocking and async recv/selective_recv,
+//! plus the `async for` iterator protocol.
 #![allow(non_local_definitions)]
 
-use crate::py::utils::{message_to_py, run_python_matcher};
+use crate::py::utils::{message_to_py, message_to_py_checked, message_to_py_obj, run_python_matcher};
 use pyo3::prelude::*;
+use pyo3_asyncio::tokio::future_into_py;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex as TokioMutex;
 
 /// A wrapper around a live MailboxReceiver for Python actors.
@@ -19,6 +22,11 @@ pub struct PyMailbox {
 impl PyMailbox {
     /// Receive the next message (Blocking).
     /// Releases the GIL while waiting. Checks for Python signals cleanly to allow Ctrl+C escapes.
+    ///
+    /// Contract: a genuinely closed mailbox (the actor is gone) returns
+    /// `None`; running out the requested `timeout` with no message raises
+    /// `TimeoutError` instead, so callers can tell "actor terminated" from
+    /// "nothing arrived yet".
     fn recv(&self, py: Python, timeout: Option<f64>) -> PyResult<PyObject> {
         let rx = self.inner.clone();
         let start = std::time::Instant::now();
@@ -32,7 +40,9 @@ impl PyMailbox {
             let actual_wait = if let Some(t) = timeout_dur {
                 let elapsed = start.elapsed();
                 if elapsed >= t {
-                    return Ok(py.None());
+                    return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                        "recv() timed out",
+                    ));
                 }
                 std::cmp::min(wait_time, t.saturating_sub(elapsed))
             } else {
@@ -44,7 +54,125 @@ impl PyMailbox {
                 crate::RUNTIME.block_on(async {
                     let fut = async {
                         let mut guard = rx.lock().await;
-                        guard.recv().await
+                        let msg = guard.recv().await;
+                        (msg, guard.is_trapping_exit())
+                    };
+                    tokio::time::timeout(actual_wait, fut).await
+                })
+            });
+
+            match res {
+                Ok((Some(msg), trap_exit)) => return message_to_py_checked(py, msg, trap_exit),
+                Ok((None, _)) => return Ok(py.None()),
+                Err(_) => {
+                    // Check if it's the end of user's requested timeout
+                    if let Some(t) = timeout_dur {
+                        if start.elapsed() >= t {
+                            return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                                "recv() timed out",
+                            ));
+                        }
+                    }
+                    // Else loop back up to check_signals
+                }
+            }
+        }
+    }
+
+    /// Receive the next message and decode its payload as MessagePack
+    /// (Blocking). Same timeout-vs-closed contract as `recv`, except a
+    /// `User` message that fails to decode raises `MsgpackDecodeError`
+    /// instead of handing back raw bytes — pairs with `PyRuntime.send_obj`.
+    /// System messages are returned exactly as `recv` would return them.
+    fn recv_obj(&self, py: Python, timeout: Option<f64>) -> PyResult<PyObject> {
+        let rx = self.inner.clone();
+        let start = std::time::Instant::now();
+        let timeout_dur = timeout.map(std::time::Duration::from_secs_f64);
+
+        loop {
+            py.check_signals()?;
+
+            let wait_time = std::time::Duration::from_millis(100);
+            let actual_wait = if let Some(t) = timeout_dur {
+                let elapsed = start.elapsed();
+                if elapsed >= t {
+                    return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                        "recv_obj() timed out",
+                    ));
+                }
+                std::cmp::min(wait_time, t.saturating_sub(elapsed))
+            } else {
+                wait_time
+            };
+
+            let res = py.allow_threads(|| {
+                crate::RUNTIME.block_on(async {
+                    let fut = async {
+                        let mut guard = rx.lock().await;
+                        let msg = guard.recv().await;
+                        (msg, guard.is_trapping_exit())
+                    };
+                    tokio::time::timeout(actual_wait, fut).await
+                })
+            });
+
+            match res {
+                Ok((Some(msg), trap_exit)) => return message_to_py_obj(py, msg, trap_exit),
+                Ok((None, _)) => return Ok(py.None()),
+                Err(_) => {
+                    if let Some(t) = timeout_dur {
+                        if start.elapsed() >= t {
+                            return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                                "recv_obj() timed out",
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Set whether this mailbox traps EXIT signals (the default). When
+    /// trapping, `recv`/`recv_async`/`async for` yield EXIT notifications as
+    /// ordinary `PySystemMessage` objects. When not trapping, a non-`Normal`
+    /// EXIT from a linked actor is raised as `ActorExit` instead.
+    fn set_trap_exit(&self, py: Python, trap: bool) {
+        let rx = self.inner.clone();
+        py.allow_threads(|| {
+            crate::RUNTIME.block_on(async {
+                rx.lock().await.set_trap_exit(trap);
+            })
+        })
+    }
+
+    /// Receive the next message whose correlation tag is in `tags` (Blocking).
+    /// Matching happens entirely in Rust over the stored tag, so unlike
+    /// `selective_recv` this does not re-acquire the GIL per buffered message.
+    /// Tag `0` is reserved for untagged messages and never matches.
+    fn recv_tagged(&self, py: Python, tags: Vec<u64>, timeout: Option<f64>) -> PyResult<PyObject> {
+        let rx = self.inner.clone();
+        let start = std::time::Instant::now();
+        let timeout_dur = timeout.map(std::time::Duration::from_secs_f64);
+
+        loop {
+            py.check_signals()?;
+
+            let wait_time = std::time::Duration::from_millis(100);
+            let actual_wait = if let Some(t) = timeout_dur {
+                let elapsed = start.elapsed();
+                if elapsed >= t {
+                    return Ok(py.None());
+                }
+                std::cmp::min(wait_time, t.saturating_sub(elapsed))
+            } else {
+                wait_time
+            };
+
+            let res = py.allow_threads(|| {
+                crate::RUNTIME.block_on(async {
+                    let fut = async {
+                        let mut guard = rx.lock().await;
+                        guard.recv_tagged(&tags).await
                     };
                     tokio::time::timeout(actual_wait, fut).await
                 })
@@ -54,20 +182,113 @@ impl PyMailbox {
                 Ok(Some(msg)) => return Ok(message_to_py(py, msg)),
                 Ok(None) => return Ok(py.None()),
                 Err(_) => {
-                    // Check if it's the end of user's requested timeout
                     if let Some(t) = timeout_dur {
                         if start.elapsed() >= t {
                             return Ok(py.None());
                         }
                     }
-                    // Else loop back up to check_signals
                 }
             }
         }
     }
 
+    /// Receive the next message (asyncio-awaitable).
+    /// Unlike `recv`, this does not spin a 100ms poll loop in a dedicated
+    /// thread: it clones the shared receiver, drives `guard.recv()` on
+    /// `RUNTIME` as a single future, and hands Python back an awaitable that
+    /// resolves when a message arrives (or `None` if the mailbox is closed).
+    /// Intended for `async def` actors: `msg = await mailbox.recv_async()`.
+    fn recv_async<'py>(&self, py: Python<'py>, timeout: Option<f64>) -> PyResult<&'py PyAny> {
+        let rx = self.inner.clone();
+        future_into_py(py, async move {
+            let fut = async {
+                let mut guard = rx.lock().await;
+                let msg = guard.recv().await;
+                (msg, guard.is_trapping_exit())
+            };
+
+            let result = if let Some(sec) = timeout {
+                match tokio::time::timeout(Duration::from_secs_f64(sec), fut).await {
+                    Ok(v) => v,
+                    Err(_) => return Python::with_gil(|py| Ok(py.None())),
+                }
+            } else {
+                fut.await
+            };
+
+            Python::with_gil(|py| match result {
+                (Some(msg), trap_exit) => message_to_py_checked(py, msg, trap_exit),
+                (None, _) => Ok(py.None()),
+            })
+        })
+    }
+
+    /// Selectively receive a message matching a Python predicate
+    /// (asyncio-awaitable). Same semantics as `selective_recv` but returns a
+    /// future instead of blocking the calling thread.
+    fn selective_recv_async<'py>(
+        &self,
+        py: Python<'py>,
+        matcher: PyObject,
+        timeout: Option<f64>,
+    ) -> PyResult<&'py PyAny> {
+        let rx = self.inner.clone();
+        future_into_py(py, async move {
+            let fut = async {
+                let mut guard = rx.lock().await;
+                guard
+                    .selective_recv(|msg| Python::with_gil(|py| run_python_matcher(py, &matcher, msg)))
+                    .await
+            };
+
+            let result = if let Some(sec) = timeout {
+                match tokio::time::timeout(Duration::from_secs_f64(sec), fut).await {
+                    Ok(v) => v,
+                    Err(_) => {
+                        return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                            "selective_recv_async() timed out",
+                        ))
+                    }
+                }
+            } else {
+                fut.await
+            };
+
+            Python::with_gil(|py| match result {
+                Some(msg) => Ok(message_to_py(py, msg)),
+                None => Ok(py.None()),
+            })
+        })
+    }
+
+    /// `async for msg in mailbox:` — returns self as the async iterator.
+    fn __aiter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    /// Drives the same recv future as `recv_async`, but raises
+    /// `StopAsyncIteration` once the channel closes instead of returning
+    /// `None`, so the mailbox composes with Python's `async for`.
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let rx = self.inner.clone();
+        future_into_py(py, async move {
+            let (msg, trap_exit) = {
+                let mut guard = rx.lock().await;
+                let msg = guard.recv().await;
+                (msg, guard.is_trapping_exit())
+            };
+            match msg {
+                Some(msg) => Python::with_gil(|py| message_to_py_checked(py, msg, trap_exit)),
+                None => Err(pyo3::exceptions::PyStopAsyncIteration::new_err(())),
+            }
+        })
+    }
+
     /// Selectively receive a message matching a Python predicate (Blocking).
     /// Releases the GIL while waiting. Checks for Python signals cleanly.
+    ///
+    /// Same timeout-vs-closed contract as `recv`: `None` means the mailbox
+    /// closed, `TimeoutError` means the timeout elapsed with no match.
     fn selective_recv(
         &self,
         py: Python,
@@ -85,7 +306,9 @@ impl PyMailbox {
             let actual_wait = if let Some(t) = timeout_dur {
                 let elapsed = start.elapsed();
                 if elapsed >= t {
-                    return Ok(py.None());
+                    return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                        "selective_recv() timed out",
+                    ));
                 }
                 std::cmp::min(wait_time, t.saturating_sub(elapsed))
             } else {
@@ -112,7 +335,9 @@ impl PyMailbox {
                 Err(_) => {
                     if let Some(t) = timeout_dur {
                         if start.elapsed() >= t {
-                            return Ok(py.None());
+                            return Err(pyo3::exceptions::PyTimeoutError::new_err(
+                                "selective_recv() timed out",
+                            ));
                         }
                     }
                 }
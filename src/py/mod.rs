@@ -4,9 +4,17 @@
 
 pub mod pool;
 pub mod utils;
+pub mod codec;
+pub mod coro;
 pub mod mailbox;
+pub mod preserves;
+pub mod promise;
+pub mod readiness;
 pub mod runtime;
+pub mod throttle;
 pub mod wrappers;
+#[cfg(feature = "sub_interpreters")]
+pub mod subinterp;
 
 // re-export a few helpers for external callers (tests, build scripts, etc.)
 pub use wrappers::{make_module, init};
@@ -3,10 +3,11 @@
 #![allow(non_local_definitions)]
 
 use crossbeam_channel as cb_channel;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::time::Duration;
+use tokio::sync::oneshot;
 
 use crate::Runtime;
 
@@ -15,22 +16,328 @@ use pyo3::prelude::*;
 use pyo3::PyObject;
 use pyo3::types::PyBytes;
 
+/// Outcome of a `call`-style pooled invocation: the callback's return value
+/// MessagePack-encoded, or its formatted exception if it raised.
+pub(crate) type CallResult = Result<Vec<u8>, String>;
+
+/// How long `GilPool::shutdown(wait=true)` waits for each worker to finish
+/// its queued backlog and exit before giving up on that thread. `JoinHandle`
+/// has no timed join, so this polls `is_finished()` instead; a worker that's
+/// still running past the deadline is left to finish on its own rather than
+/// blocking the caller forever.
+const SHUTDOWN_JOIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Backpressure behavior for a bounded GIL worker queue once it's full.
+/// Mirrors `MailboxPolicy::Block` vs. `DropNew` one layer up, at the
+/// worker-queue boundary instead of the actor mailbox.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum QueuePolicy {
+    /// Suspend the submitting task until a slot frees up.
+    Block,
+    /// Drop the task immediately instead of suspending the actor's mailbox
+    /// loop. A dropped `call`-style task still fails its `JoinHandle` with
+    /// an error rather than leaving it to hang forever; the drop itself is
+    /// silent otherwise (only the counter moves).
+    DropNewest,
+    /// Like `DropNewest`, but a dropped fire-and-forget task is also logged,
+    /// since a `send` has no `JoinHandle` to surface the failure through and
+    /// would otherwise vanish unobserved. A dropped `call`-style task is not
+    /// logged; it fails its `JoinHandle` as under `DropNewest`.
+    Err,
+}
+
+/// Parse the queue policy name stored on `Runtime` (set via
+/// `PyRuntime::set_release_gil_queue_policy`) back into a `QueuePolicy`.
+/// Unrecognized names fall back to `Block`, the pre-existing behavior, so a
+/// typo degrades to "never drop" rather than silently dropping tasks.
+fn parse_queue_policy(name: &str) -> QueuePolicy {
+    match name {
+        "drop_newest" => QueuePolicy::DropNewest,
+        "err" => QueuePolicy::Err,
+        _ => QueuePolicy::Block,
+    }
+}
+
+/// Total tasks dropped across every bounded GIL worker queue (dedicated or
+/// shared) since process start, regardless of which policy dropped them.
+/// Paired with each queue's own depth/capacity via `PoolSender::{depth,capacity}`
+/// to let operators observe saturation.
+static RELEASE_GIL_QUEUE_DROPPED: AtomicU64 = AtomicU64::new(0);
+
+/// Sending half of a GIL worker queue, bounded or not. Bounded queues honor
+/// `QueuePolicy` when full; unbounded queues behave exactly as before this
+/// existed.
+#[derive(Clone)]
+pub(crate) enum PoolSender {
+    Unbounded(cb_channel::Sender<PoolTask>),
+    Bounded(cb_channel::Sender<PoolTask>, QueuePolicy),
+}
+
+impl PoolSender {
+    /// Submit `task` to the queue. Under `Block`, suspends (via
+    /// `spawn_blocking`, so the calling async task's executor thread isn't
+    /// tied up) until a slot is free. Under `DropNewest`/`Err`, drops the
+    /// incoming task immediately rather than wait once the queue is full.
+    pub(crate) async fn submit(&self, task: PoolTask) {
+        match self {
+            PoolSender::Unbounded(tx) => {
+                let _ = tx.send(task);
+            }
+            PoolSender::Bounded(tx, QueuePolicy::Block) => {
+                let tx = tx.clone();
+                let _ = tokio::task::spawn_blocking(move || tx.send(task)).await;
+            }
+            PoolSender::Bounded(tx, QueuePolicy::DropNewest) => {
+                if let Err(cb_channel::TrySendError::Full(task)) = tx.try_send(task) {
+                    fail_dropped(task, false);
+                }
+            }
+            PoolSender::Bounded(tx, QueuePolicy::Err) => {
+                if let Err(cb_channel::TrySendError::Full(task)) = tx.try_send(task) {
+                    fail_dropped(task, true);
+                }
+            }
+        }
+    }
+
+    /// The underlying crossbeam sender, bypassing the queue policy. Used
+    /// only for internal control messages (`PoolTask::Shutdown`) that must
+    /// never be dropped by `DropNewest`/`Err` or parked behind `Block`'s
+    /// `spawn_blocking`.
+    fn raw(&self) -> &cb_channel::Sender<PoolTask> {
+        match self {
+            PoolSender::Unbounded(tx) => tx,
+            PoolSender::Bounded(tx, _) => tx,
+        }
+    }
+
+    /// Number of tasks currently queued, for saturation monitoring.
+    pub(crate) fn depth(&self) -> usize {
+        self.raw().len()
+    }
+
+    /// The queue's bound, or `None` if unbounded.
+    pub(crate) fn capacity(&self) -> Option<usize> {
+        match self {
+            PoolSender::Unbounded(_) => None,
+            PoolSender::Bounded(tx, _) => tx.capacity(),
+        }
+    }
+}
+
+/// Fulfill a dropped `call`-style task's reply with an error instead of
+/// leaving its `JoinHandle` unresolved; plain `send` tasks have no reply to
+/// fulfill and are discarded. Always counts the drop; `log` additionally
+/// eprintln's it, for `QueuePolicy::Err` surfacing a failure that otherwise
+/// has nowhere to go.
+fn fail_dropped(task: PoolTask, log: bool) {
+    RELEASE_GIL_QUEUE_DROPPED.fetch_add(1, Ordering::Relaxed);
+    let had_reply = matches!(task, PoolTask::Execute { reply: Some(_), .. });
+    if let PoolTask::Execute { reply: Some(reply), .. } = task {
+        let _ = reply.send(Err(
+            "worker queue full; task dropped under release_gil queue policy".to_string(),
+        ));
+    }
+    if log && !had_reply {
+        eprintln!("[Iris] release_gil worker queue full; task dropped");
+    }
+}
+
+/// `(depth, capacity, total_dropped)` for the shared GIL worker pool, or
+/// `None` if it hasn't been created yet. `total_dropped` is process-wide
+/// across every bounded queue (dedicated or shared), not just this pool's.
+#[cfg(feature = "pyo3")]
+pub(crate) fn queue_stats() -> Option<(usize, Option<usize>, u64)> {
+    GIL_WORKER_POOL.get().map(|pool| {
+        (
+            pool.sender.depth(),
+            pool.sender.capacity(),
+            RELEASE_GIL_QUEUE_DROPPED.load(Ordering::Relaxed),
+        )
+    })
+}
+
 /// Task variants sent to dedicated or pooled GIL workers.
 #[cfg(feature = "pyo3")]
 pub(crate) enum PoolTask {
     Execute {
         behavior: Arc<parking_lot::RwLock<PyObject>>,
         bytes: bytes::Bytes,
+        /// Set for a `call`-style invocation; the worker fulfills it with
+        /// the callback's return value instead of discarding it. `None` for
+        /// plain fire-and-forget `send` tasks.
+        reply: Option<oneshot::Sender<CallResult>>,
     },
     HotSwap {
         behavior: Arc<parking_lot::RwLock<PyObject>>,
         ptr: usize,
     },
+    /// Sentinel that tells exactly one worker to exit after draining every
+    /// task queued ahead of it. `GilPool::shutdown` sends one per thread.
+    Shutdown,
+}
+
+/// Where an uncaught Python actor exception gets routed once
+/// `execute_and_reply` catches it, instead of vanishing into stderr.
+/// Configured process-wide via `PyRuntime::set_actor_error_sink`; `None`
+/// (the default) keeps the original eprintln-only behavior.
+#[cfg(feature = "pyo3")]
+#[derive(Clone)]
+pub(crate) enum ErrorSink {
+    /// MessagePack-encode the error as a `{kind, message, traceback,
+    /// behavior_id}` map and deliver it as a `Message::User` to `pid` on
+    /// `rt` — the same dict-shaped wire contract `send_obj`/`recv_obj` use,
+    /// so a supervisor actor just calls `recv_obj`.
+    Supervisor { rt: Arc<Runtime>, pid: u64 },
+    /// Call this Python callable with `(kind, message, traceback,
+    /// behavior_id)` positional args.
+    Callback(Arc<PyObject>),
+}
+
+#[cfg(feature = "pyo3")]
+static ACTOR_ERROR_SINK: OnceLock<parking_lot::RwLock<Option<ErrorSink>>> = OnceLock::new();
+
+#[cfg(feature = "pyo3")]
+fn actor_error_sink() -> &'static parking_lot::RwLock<Option<ErrorSink>> {
+    ACTOR_ERROR_SINK.get_or_init(|| parking_lot::RwLock::new(None))
+}
+
+/// Configure (or, with `None`, clear) the process-wide sink every worker
+/// reports uncaught actor exceptions to.
+#[cfg(feature = "pyo3")]
+pub(crate) fn set_actor_error_sink(sink: Option<ErrorSink>) {
+    *actor_error_sink().write() = sink;
+}
+
+/// Detail of one uncaught Python actor exception, captured while the GIL is
+/// still held so formatting it can't race the error being cleared.
+/// `behavior_id` is the failing `behavior`'s current `PyObject` pointer
+/// identity — stable across calls, and changing on `HotSwap` — so a
+/// supervisor can correlate repeated failures back to one behavior and
+/// decide to hot-swap, restart, or escalate.
+#[cfg(feature = "pyo3")]
+#[derive(Clone, Debug)]
+pub(crate) struct ActorError {
+    pub(crate) behavior_id: usize,
+    pub(crate) kind: String,
+    pub(crate) message: String,
+    pub(crate) traceback: String,
+}
+
+#[cfg(feature = "pyo3")]
+impl ActorError {
+    fn capture(py: Python, behavior_id: usize, err: &PyErr) -> Self {
+        let kind = err
+            .get_type(py)
+            .name()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|_| "Exception".to_string());
+        let message = err.value(py).to_string();
+        let traceback = err
+            .traceback(py)
+            .and_then(|tb| tb.format().ok())
+            .unwrap_or_default();
+        ActorError { behavior_id, kind, message, traceback }
+    }
+
+    /// MessagePack-encode as `{"kind": ..., "message": ..., "traceback":
+    /// ..., "behavior_id": ...}`, the same map shape `send_obj` produces for
+    /// a Python dict, so a supervisor actor decodes it with plain
+    /// `recv_obj`.
+    fn to_msgpack(&self) -> Vec<u8> {
+        let value = rmpv::Value::Map(vec![
+            (rmpv::Value::from("kind"), rmpv::Value::from(self.kind.as_str())),
+            (
+                rmpv::Value::from("message"),
+                rmpv::Value::from(self.message.as_str()),
+            ),
+            (
+                rmpv::Value::from("traceback"),
+                rmpv::Value::from(self.traceback.as_str()),
+            ),
+            (
+                rmpv::Value::from("behavior_id"),
+                rmpv::Value::from(self.behavior_id as u64),
+            ),
+        ]);
+        let mut buf = Vec::new();
+        let _ = rmpv::encode::write_value(&mut buf, &value);
+        buf
+    }
+}
+
+/// Report `err` to the configured `ErrorSink`, if any; returns whether a
+/// sink was configured (so the caller can fall back to `eprintln` when it
+/// wasn't). Holds the GIL for the duration: capturing the traceback and
+/// (for `Callback`) invoking Python both need it, and `Supervisor` delivery
+/// is itself a plain non-blocking mailbox send.
+#[cfg(feature = "pyo3")]
+fn report_actor_error(py: Python, behavior_id: usize, err: &PyErr) -> bool {
+    let sink = actor_error_sink().read().clone();
+    let Some(sink) = sink else { return false };
+    let actor_err = ActorError::capture(py, behavior_id, err);
+    match sink {
+        ErrorSink::Supervisor { rt, pid } => {
+            let bytes = bytes::Bytes::from(actor_err.to_msgpack());
+            let _ = rt.send(pid, crate::mailbox::Message::User(bytes));
+        }
+        ErrorSink::Callback(cb) => {
+            let obj = cb.as_ref(py);
+            if let Err(e) = obj.call1((
+                actor_err.kind.as_str(),
+                actor_err.message.as_str(),
+                actor_err.traceback.as_str(),
+                actor_err.behavior_id,
+            )) {
+                eprintln!("[Iris] actor error sink callback raised: {}", e);
+                e.print(py);
+            }
+        }
+    }
+    true
+}
+
+/// Call `behavior` with `bytes` and, if `reply` is present, fulfill it with
+/// the MessagePack-encoded return value (or the formatted exception). Shared
+/// by the dedicated-thread and shared-pool workers so the call/reply
+/// bookkeeping lives in one place.
+#[cfg(feature = "pyo3")]
+fn execute_and_reply(
+    behavior: &Arc<parking_lot::RwLock<PyObject>>,
+    bytes: &bytes::Bytes,
+    reply: Option<oneshot::Sender<CallResult>>,
+) {
+    Python::with_gil(|py| {
+        let guard = behavior.read();
+        let cb = guard.as_ref(py);
+        let behavior_id = guard.as_ptr() as usize;
+        let pybytes = PyBytes::new(py, bytes);
+        match cb.call1((pybytes,)) {
+            Ok(ret) => {
+                if let Some(reply) = reply {
+                    let result = super::codec::py_to_msgpack(py, ret).map_err(|e| e.to_string());
+                    let _ = reply.send(result);
+                }
+            }
+            Err(e) => {
+                if !report_actor_error(py, behavior_id, &e) {
+                    eprintln!("[Iris] Python actor exception: {}", e);
+                    e.print(py);
+                }
+                if let Some(reply) = reply {
+                    let _ = reply.send(Err(e.to_string()));
+                }
+            }
+        }
+    });
 }
 
 #[cfg(feature = "pyo3")]
 pub(crate) struct GilPool {
-    pub(crate) sender: cb_channel::Sender<PoolTask>,
+    pub(crate) sender: PoolSender,
+    /// Taken by `shutdown` to join every worker after it drains. Emptied
+    /// once shutdown has been requested, so a second call is a no-op.
+    threads: std::sync::Mutex<Vec<std::thread::JoinHandle<()>>>,
 }
 
 #[cfg(feature = "pyo3")]
@@ -38,30 +345,35 @@ pub(crate) static GIL_WORKER_POOL: OnceLock<Arc<GilPool>> = OnceLock::new();
 
 #[cfg(feature = "pyo3")]
 impl GilPool {
-    fn new(size: usize) -> Self {
-        let (tx, rx) = cb_channel::unbounded::<PoolTask>();
+    /// `capacity == 0` means unbounded, matching `get_release_gil_queue_config`'s
+    /// convention. `policy` is ignored when unbounded (an unbounded queue never
+    /// fills, so there's nothing for a full-queue policy to govern).
+    fn new(size: usize, capacity: usize, policy: QueuePolicy) -> Self {
+        let (tx, rx) = if capacity == 0 {
+            cb_channel::unbounded::<PoolTask>()
+        } else {
+            cb_channel::bounded::<PoolTask>(capacity)
+        };
+        let sender = if capacity == 0 {
+            PoolSender::Unbounded(tx.clone())
+        } else {
+            PoolSender::Bounded(tx.clone(), policy)
+        };
+        let mut threads = Vec::with_capacity(size);
         for _ in 0..size {
             let rx = rx.clone();
-            std::thread::spawn(move || {
+            let handle = std::thread::spawn(move || {
                 loop {
                     if unsafe { pyo3::ffi::Py_IsInitialized() } == 0 {
                         break;
                     }
                     match rx.recv_timeout(Duration::from_millis(100)) {
                         Ok(task) => match task {
-                            PoolTask::Execute { behavior, bytes } => {
+                            PoolTask::Execute { behavior, bytes, reply } => {
                                 if unsafe { pyo3::ffi::Py_IsInitialized() } == 0 {
                                     break;
                                 }
-                                Python::with_gil(|py| {
-                                    let guard = behavior.read();
-                                    let cb = guard.as_ref(py);
-                                    let pybytes = PyBytes::new(py, &bytes);
-                                    if let Err(e) = cb.call1((pybytes,)) {
-                                        eprintln!("[Iris] Python actor exception: {}", e);
-                                        e.print(py);
-                                    }
-                                });
+                                execute_and_reply(&behavior, &bytes, reply);
                             }
                             PoolTask::HotSwap { behavior, ptr } => {
                                 if unsafe { pyo3::ffi::Py_IsInitialized() } == 0 {
@@ -75,14 +387,63 @@ impl GilPool {
                                     *behavior.write() = new_obj;
                                 });
                             }
+                            PoolTask::Shutdown => break,
                         },
                         Err(cb_channel::RecvTimeoutError::Timeout) => continue,
                         Err(cb_channel::RecvTimeoutError::Disconnected) => break,
                     }
                 }
             });
+            threads.push(handle);
+        }
+        GilPool { sender, threads: std::sync::Mutex::new(threads) }
+    }
+
+    /// Ask every worker to drain its remaining queued tasks and exit, then
+    /// (if `wait`) join each thread, up to `SHUTDOWN_JOIN_TIMEOUT` per
+    /// thread. One `Shutdown` sentinel is queued per worker so tasks
+    /// already ahead of it in line still run; anything submitted after this
+    /// call races with the workers exiting and may be left unprocessed. A
+    /// second call is a no-op (the threads are only joined/signalled once).
+    /// Sentinels go out via `PoolSender::raw` so a `DropNewest`/`Err` can't
+    /// drop them and `Block` can't park them.
+    ///
+    /// Must be called with the GIL released (see `shutdown_gil_pool`):
+    /// a worker still has `Execute`/`HotSwap` tasks ahead of its sentinel
+    /// may need to reacquire the GIL to run them, which would deadlock
+    /// against a caller blocked here while holding it.
+    pub(crate) fn shutdown(&self, wait: bool) {
+        let threads = std::mem::take(&mut *self.threads.lock().unwrap());
+        for _ in 0..threads.len() {
+            let _ = self.sender.raw().send(PoolTask::Shutdown);
+        }
+        if wait {
+            for handle in threads {
+                let deadline = std::time::Instant::now() + SHUTDOWN_JOIN_TIMEOUT;
+                while !handle.is_finished() && std::time::Instant::now() < deadline {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                if handle.is_finished() {
+                    let _ = handle.join();
+                }
+                // Else: this worker is still draining past the timeout.
+                // Leave it running rather than blocking the caller
+                // indefinitely — it'll exit on its own once its backlog
+                // clears.
+            }
         }
-        GilPool { sender: tx }
+    }
+}
+
+/// Gracefully shut down the shared GIL worker pool, if one was ever
+/// created. No-op (not an error) when no pool has been spun up yet. Callers
+/// holding the GIL must release it first (see `GilPool::shutdown`'s doc
+/// comment) to avoid deadlocking against a worker that needs to reacquire
+/// it to drain its backlog.
+#[cfg(feature = "pyo3")]
+pub(crate) fn shutdown_gil_pool(wait: bool) {
+    if let Some(pool) = GIL_WORKER_POOL.get() {
+        pool.shutdown(wait);
     }
 }
 
@@ -95,7 +456,7 @@ pub(crate) fn make_release_gil_channel(
     rt: &Runtime,
     release: bool,
     behavior: Arc<parking_lot::RwLock<PyObject>>,
-) -> PyResult<Option<cb_channel::Sender<PoolTask>>> {
+) -> PyResult<Option<PoolSender>> {
     if !release {
         return Ok(None);
     }
@@ -106,6 +467,8 @@ pub(crate) fn make_release_gil_channel(
 
     let (max_threads, pool_size) = rt.get_release_gil_limits();
     let strict = rt.is_release_gil_strict();
+    let (queue_capacity, queue_policy_name) = rt.get_release_gil_queue_config();
+    let queue_policy = parse_queue_policy(&queue_policy_name);
 
     let prev = RELEASE_GIL_THREADS.fetch_add(1, Ordering::SeqCst);
     if prev >= max_threads {
@@ -116,12 +479,21 @@ pub(crate) fn make_release_gil_channel(
             ));
         }
         let _ = GIL_WORKER_POOL
-            .get_or_init(|| Arc::new(GilPool::new(pool_size)))
+            .get_or_init(|| Arc::new(GilPool::new(pool_size, queue_capacity, queue_policy)))
             .clone();
         return Ok(None);
     }
 
-    let (tx, rx) = cb_channel::unbounded::<PoolTask>();
+    let (tx, rx) = if queue_capacity == 0 {
+        cb_channel::unbounded::<PoolTask>()
+    } else {
+        cb_channel::bounded::<PoolTask>(queue_capacity)
+    };
+    let sender = if queue_capacity == 0 {
+        PoolSender::Unbounded(tx)
+    } else {
+        PoolSender::Bounded(tx, queue_policy)
+    };
     let _b_thread = behavior.clone();
     std::thread::spawn(move || {
         loop {
@@ -131,19 +503,11 @@ pub(crate) fn make_release_gil_channel(
             }
             match rx.recv_timeout(Duration::from_millis(100)) {
                 Ok(task) => match task {
-                    PoolTask::Execute { behavior, bytes } => {
+                    PoolTask::Execute { behavior, bytes, reply } => {
                         if unsafe { pyo3::ffi::Py_IsInitialized() } == 0 {
                             continue;
                         }
-                        Python::with_gil(|py| {
-                            let guard = behavior.read();
-                            let cb = guard.as_ref(py);
-                            let pybytes = PyBytes::new(py, &bytes);
-                            if let Err(e) = cb.call1((pybytes,)) {
-                                eprintln!("[Iris] Python actor exception: {}", e);
-                                e.print(py);
-                            }
-                        });
+                        execute_and_reply(&behavior, &bytes, reply);
                     }
                     PoolTask::HotSwap { behavior, ptr } => {
                         if unsafe { pyo3::ffi::Py_IsInitialized() } == 0 {
@@ -157,6 +521,11 @@ pub(crate) fn make_release_gil_channel(
                             *behavior.write() = new_obj;
                         });
                     }
+                    // This dedicated thread is never handed a `Shutdown`
+                    // sentinel (only `GilPool::shutdown` sends those); it
+                    // already drains and exits on its own once the owning
+                    // actor drops `tx`, producing `Disconnected` below.
+                    PoolTask::Shutdown => break,
                 },
                 Err(cb_channel::RecvTimeoutError::Timeout) => continue,
                 Err(cb_channel::RecvTimeoutError::Disconnected) => {
@@ -166,5 +535,48 @@ pub(crate) fn make_release_gil_channel(
             }
         }
     });
-    Ok(Some(tx))
+    Ok(Some(sender))
+}
+
+/// Handle returned by `PyRuntime.call`, representing the in-flight return
+/// value of a single pooled-actor invocation. Mirrors a `std::thread::
+/// JoinHandle`: `result()` consumes it, so it can only be awaited once.
+#[cfg(feature = "pyo3")]
+#[pyclass]
+pub struct PyJoinHandle {
+    rx: Arc<tokio::sync::Mutex<Option<oneshot::Receiver<CallResult>>>>,
+}
+
+#[cfg(feature = "pyo3")]
+impl PyJoinHandle {
+    pub(crate) fn new(rx: oneshot::Receiver<CallResult>) -> Self {
+        PyJoinHandle { rx: Arc::new(tokio::sync::Mutex::new(Some(rx))) }
+    }
+}
+
+#[cfg(feature = "pyo3")]
+#[pymethods]
+impl PyJoinHandle {
+    /// Await the callback's return value. Raises `RuntimeError` if the
+    /// callback itself raised, if the actor was dropped before replying, or
+    /// if `result()` has already been awaited once.
+    fn result<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let rx = self.rx.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let receiver = rx
+                .lock()
+                .await
+                .take()
+                .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err(
+                    "JoinHandle.result() already awaited",
+                ))?;
+            match receiver.await {
+                Ok(Ok(bytes)) => Python::with_gil(|py| super::codec::msgpack_to_py(py, &bytes)),
+                Ok(Err(msg)) => Err(pyo3::exceptions::PyRuntimeError::new_err(msg)),
+                Err(_) => Err(pyo3::exceptions::PyRuntimeError::new_err(
+                    "pooled actor was dropped before replying",
+                )),
+            }
+        })
+    }
 }
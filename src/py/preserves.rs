@@ -0,0 +1,444 @@
+// src/py/preserves.rs
+//! Preserves-style canonical wire format for messages crossing a node
+//! boundary.
+//!
+//! `codec.rs`'s MessagePack round-trip is fine for a single process, but it
+//! has no way to say "this integer is actually a pid" — which matters once
+//! `cluster` (see `crate::cluster`) starts forwarding messages between
+//! nodes, since a bare pid is meaningless without knowing which node it's
+//! local to on the other end. This module adds a second, schema-richer
+//! grammar alongside it: atoms, records with a labeled tag, sequences,
+//! sets, dictionaries, and an embedded-reference slot that a serialized pid
+//! occupies instead of a plain integer, so the receiving node can rewrite
+//! it into a live remote handle on arrival instead of just depositing an
+//! opaque number.
+//!
+//! Python mapping (encode side):
+//! - `None` -> the symbol `null`; `bool`/`int`/`float`/`str`/`bytes` map to
+//!   their obvious atoms.
+//! - `list` -> a sequence; `set`/`frozenset` -> a set.
+//! - `tuple` -> a record if its first element is a `str` (that string is
+//!   the label, the rest are fields), otherwise a plain sequence — the
+//!   same shape MessagePack's codec already gives `list`/`tuple`, with the
+//!   labeled-tuple case carved out for records since Python has no
+//!   separate record literal to spare for it.
+//! - `dict` -> a dictionary.
+//! - `Embedded` -> an embedded capability reference. Construct one
+//!   explicitly (`Embedded(pid)`) to mark an integer as a pid rather than a
+//!   plain number; decoding one back hands you the same wrapper rather than
+//!   silently rewriting it into a remote handle, since resolving *which*
+//!   node it became live on is `cluster`'s job at the transport layer, not
+//!   this codec's.
+#![allow(non_local_definitions)]
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{
+    PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyList, PyLong, PySet, PyString, PyTuple,
+};
+
+/// Raised when a received frame does not decode as a well-formed Preserves
+/// document, or decodes but is truncated/corrupt partway through.
+pyo3::create_exception!(iris, PreservesDecodeError, pyo3::exceptions::PyValueError);
+
+/// A pid wrapped for embedding in a Preserves document, rather than being
+/// serialized as a plain integer. See the module doc comment.
+#[pyclass]
+#[derive(Clone)]
+pub struct Embedded {
+    #[pyo3(get)]
+    pub pid: u64,
+}
+
+#[pymethods]
+impl Embedded {
+    #[new]
+    fn new(pid: u64) -> Self {
+        Self { pid }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Embedded({})", self.pid)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.pid == other.pid
+    }
+}
+
+/// The Preserves-style value grammar: atoms, records, sequences, sets,
+/// dictionaries, and embedded capability references.
+#[derive(Debug, Clone, PartialEq)]
+enum PValue {
+    Boolean(bool),
+    Double(f64),
+    SignedInteger(i64),
+    String(String),
+    ByteString(Vec<u8>),
+    Symbol(String),
+    Record { label: String, fields: Vec<PValue> },
+    Sequence(Vec<PValue>),
+    Set(Vec<PValue>),
+    Dictionary(Vec<(PValue, PValue)>),
+    Embedded(u64),
+}
+
+const TAG_BOOL_FALSE: u8 = 0x00;
+const TAG_BOOL_TRUE: u8 = 0x01;
+const TAG_DOUBLE: u8 = 0x02;
+const TAG_SIGNED_INTEGER: u8 = 0x03;
+const TAG_STRING: u8 = 0x04;
+const TAG_BYTE_STRING: u8 = 0x05;
+const TAG_SYMBOL: u8 = 0x06;
+const TAG_RECORD: u8 = 0x07;
+const TAG_SEQUENCE: u8 = 0x08;
+const TAG_SET: u8 = 0x09;
+const TAG_DICTIONARY: u8 = 0x0a;
+const TAG_EMBEDDED: u8 = 0x0b;
+
+fn write_len(buf: &mut Vec<u8>, len: usize) {
+    buf.extend_from_slice(&(len as u32).to_be_bytes());
+}
+
+fn write_value(buf: &mut Vec<u8>, value: &PValue) {
+    match value {
+        PValue::Boolean(false) => buf.push(TAG_BOOL_FALSE),
+        PValue::Boolean(true) => buf.push(TAG_BOOL_TRUE),
+        PValue::Double(f) => {
+            buf.push(TAG_DOUBLE);
+            buf.extend_from_slice(&f.to_be_bytes());
+        }
+        PValue::SignedInteger(i) => {
+            buf.push(TAG_SIGNED_INTEGER);
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        PValue::String(s) => {
+            buf.push(TAG_STRING);
+            write_len(buf, s.len());
+            buf.extend_from_slice(s.as_bytes());
+        }
+        PValue::ByteString(b) => {
+            buf.push(TAG_BYTE_STRING);
+            write_len(buf, b.len());
+            buf.extend_from_slice(b);
+        }
+        PValue::Symbol(s) => {
+            buf.push(TAG_SYMBOL);
+            write_len(buf, s.len());
+            buf.extend_from_slice(s.as_bytes());
+        }
+        PValue::Record { label, fields } => {
+            buf.push(TAG_RECORD);
+            write_len(buf, label.len());
+            buf.extend_from_slice(label.as_bytes());
+            write_len(buf, fields.len());
+            for field in fields {
+                write_value(buf, field);
+            }
+        }
+        PValue::Sequence(items) => {
+            buf.push(TAG_SEQUENCE);
+            write_len(buf, items.len());
+            for item in items {
+                write_value(buf, item);
+            }
+        }
+        PValue::Set(items) => {
+            buf.push(TAG_SET);
+            write_len(buf, items.len());
+            for item in items {
+                write_value(buf, item);
+            }
+        }
+        PValue::Dictionary(entries) => {
+            buf.push(TAG_DICTIONARY);
+            write_len(buf, entries.len());
+            for (k, v) in entries {
+                write_value(buf, k);
+                write_value(buf, v);
+            }
+        }
+        PValue::Embedded(pid) => {
+            buf.push(TAG_EMBEDDED);
+            buf.extend_from_slice(&pid.to_be_bytes());
+        }
+    }
+}
+
+fn take_bytes<'a>(cursor: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+    if cursor.len() < len {
+        return Err("truncated Preserves frame".to_string());
+    }
+    let (head, tail) = cursor.split_at(len);
+    *cursor = tail;
+    Ok(head)
+}
+
+fn take_u8(cursor: &mut &[u8]) -> Result<u8, String> {
+    Ok(take_bytes(cursor, 1)?[0])
+}
+
+fn take_len(cursor: &mut &[u8]) -> Result<usize, String> {
+    let raw = take_bytes(cursor, 4)?;
+    Ok(u32::from_be_bytes(raw.try_into().unwrap()) as usize)
+}
+
+fn take_string(cursor: &mut &[u8]) -> Result<String, String> {
+    let len = take_len(cursor)?;
+    let raw = take_bytes(cursor, len)?;
+    String::from_utf8(raw.to_vec()).map_err(|e| format!("invalid UTF-8 in frame: {}", e))
+}
+
+/// Read a `TAG_RECORD`/`TAG_SEQUENCE`/`TAG_SET`/`TAG_DICTIONARY` element
+/// count and bound it against what's actually left in `cursor`, the same
+/// way `network.rs`'s `read_payload` bounds a frame length before
+/// allocating for it. Every element is at least `min_bytes_per_item` bytes
+/// on the wire (one tag byte for a field/item, two for a dictionary's
+/// key+value pair), so a `count` that can't possibly fit is a malformed or
+/// hostile frame rather than something worth a multi-gigabyte
+/// `Vec::with_capacity` guess.
+fn take_count(cursor: &mut &[u8], min_bytes_per_item: usize) -> Result<usize, String> {
+    let count = take_len(cursor)?;
+    if count > cursor.len() / min_bytes_per_item {
+        return Err(format!(
+            "Preserves element count {count} exceeds the {}-byte frame remaining",
+            cursor.len()
+        ));
+    }
+    Ok(count)
+}
+
+fn read_value(cursor: &mut &[u8]) -> Result<PValue, String> {
+    match take_u8(cursor)? {
+        TAG_BOOL_FALSE => Ok(PValue::Boolean(false)),
+        TAG_BOOL_TRUE => Ok(PValue::Boolean(true)),
+        TAG_DOUBLE => {
+            let raw = take_bytes(cursor, 8)?;
+            Ok(PValue::Double(f64::from_be_bytes(raw.try_into().unwrap())))
+        }
+        TAG_SIGNED_INTEGER => {
+            let raw = take_bytes(cursor, 8)?;
+            Ok(PValue::SignedInteger(i64::from_be_bytes(
+                raw.try_into().unwrap(),
+            )))
+        }
+        TAG_STRING => Ok(PValue::String(take_string(cursor)?)),
+        TAG_BYTE_STRING => {
+            let len = take_len(cursor)?;
+            Ok(PValue::ByteString(take_bytes(cursor, len)?.to_vec()))
+        }
+        TAG_SYMBOL => Ok(PValue::Symbol(take_string(cursor)?)),
+        TAG_RECORD => {
+            let label = take_string(cursor)?;
+            let count = take_count(cursor, 1)?;
+            let mut fields = Vec::with_capacity(count);
+            for _ in 0..count {
+                fields.push(read_value(cursor)?);
+            }
+            Ok(PValue::Record { label, fields })
+        }
+        TAG_SEQUENCE => {
+            let count = take_count(cursor, 1)?;
+            let mut items = Vec::with_capacity(count);
+            for _ in 0..count {
+                items.push(read_value(cursor)?);
+            }
+            Ok(PValue::Sequence(items))
+        }
+        TAG_SET => {
+            let count = take_count(cursor, 1)?;
+            let mut items = Vec::with_capacity(count);
+            for _ in 0..count {
+                items.push(read_value(cursor)?);
+            }
+            Ok(PValue::Set(items))
+        }
+        TAG_DICTIONARY => {
+            let count = take_count(cursor, 2)?;
+            let mut entries = Vec::with_capacity(count);
+            for _ in 0..count {
+                let k = read_value(cursor)?;
+                let v = read_value(cursor)?;
+                entries.push((k, v));
+            }
+            Ok(PValue::Dictionary(entries))
+        }
+        TAG_EMBEDDED => {
+            let raw = take_bytes(cursor, 8)?;
+            Ok(PValue::Embedded(u64::from_be_bytes(raw.try_into().unwrap())))
+        }
+        other => Err(format!("unknown Preserves tag byte {:#04x}", other)),
+    }
+}
+
+fn py_to_pvalue(py: Python, obj: &PyAny) -> PyResult<PValue> {
+    if obj.is_none() {
+        return Ok(PValue::Symbol("null".to_string()));
+    }
+    if let Ok(embedded) = obj.extract::<PyRef<Embedded>>() {
+        return Ok(PValue::Embedded(embedded.pid));
+    }
+    if let Ok(b) = obj.downcast::<PyBool>() {
+        return Ok(PValue::Boolean(b.is_true()));
+    }
+    if let Ok(i) = obj.downcast::<PyLong>() {
+        return Ok(PValue::SignedInteger(i.extract::<i64>()?));
+    }
+    if let Ok(f) = obj.downcast::<PyFloat>() {
+        return Ok(PValue::Double(f.extract::<f64>()?));
+    }
+    if let Ok(s) = obj.downcast::<PyString>() {
+        return Ok(PValue::String(s.to_str()?.to_string()));
+    }
+    if let Ok(b) = obj.downcast::<PyBytes>() {
+        return Ok(PValue::ByteString(b.as_bytes().to_vec()));
+    }
+    if let Ok(list) = obj.downcast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| py_to_pvalue(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(PValue::Sequence(items));
+    }
+    if let Ok(tuple) = obj.downcast::<PyTuple>() {
+        if let Some(first) = tuple.iter().next() {
+            if let Ok(label) = first.downcast::<PyString>() {
+                let fields = tuple
+                    .iter()
+                    .skip(1)
+                    .map(|item| py_to_pvalue(py, item))
+                    .collect::<PyResult<Vec<_>>>()?;
+                return Ok(PValue::Record {
+                    label: label.to_str()?.to_string(),
+                    fields,
+                });
+            }
+        }
+        let items = tuple
+            .iter()
+            .map(|item| py_to_pvalue(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(PValue::Sequence(items));
+    }
+    if let Ok(set) = obj.downcast::<PySet>() {
+        let items = set
+            .iter()
+            .map(|item| py_to_pvalue(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(PValue::Set(items));
+    }
+    if let Ok(set) = obj.downcast::<PyFrozenSet>() {
+        let items = set
+            .iter()
+            .map(|item| py_to_pvalue(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(PValue::Set(items));
+    }
+    if let Ok(dict) = obj.downcast::<PyDict>() {
+        let mut entries = Vec::with_capacity(dict.len());
+        for (k, v) in dict.iter() {
+            entries.push((py_to_pvalue(py, k)?, py_to_pvalue(py, v)?));
+        }
+        return Ok(PValue::Dictionary(entries));
+    }
+    Err(PyTypeError::new_err(format!(
+        "encode_message: unsupported type {} (only None/bool/int/float/str/bytes/list/tuple/set/frozenset/dict/Embedded are supported)",
+        obj.get_type().name()?
+    )))
+}
+
+fn pvalue_to_py(py: Python, value: &PValue) -> PyObject {
+    match value {
+        PValue::Boolean(b) => b.into_py(py),
+        PValue::Double(f) => f.into_py(py),
+        PValue::SignedInteger(i) => i.into_py(py),
+        PValue::String(s) => s.into_py(py),
+        PValue::ByteString(b) => PyBytes::new(py, b).into_py(py),
+        PValue::Symbol(s) if s == "null" => py.None(),
+        PValue::Symbol(s) => s.into_py(py),
+        PValue::Record { label, fields } => {
+            let mut items: Vec<PyObject> = Vec::with_capacity(fields.len() + 1);
+            items.push(label.into_py(py));
+            items.extend(fields.iter().map(|f| pvalue_to_py(py, f)));
+            PyTuple::new(py, items).into_py(py)
+        }
+        PValue::Sequence(items) => {
+            let converted: Vec<PyObject> = items.iter().map(|v| pvalue_to_py(py, v)).collect();
+            converted.into_py(py)
+        }
+        PValue::Set(items) => {
+            let converted: Vec<PyObject> = items.iter().map(|v| pvalue_to_py(py, v)).collect();
+            PySet::new(py, &converted)
+                .map(|s| s.into_py(py))
+                .unwrap_or_else(|_| py.None())
+        }
+        PValue::Dictionary(entries) => {
+            let dict = PyDict::new(py);
+            for (k, v) in entries {
+                let _ = dict.set_item(pvalue_to_py(py, k), pvalue_to_py(py, v));
+            }
+            dict.into_py(py)
+        }
+        PValue::Embedded(pid) => Py::new(py, Embedded { pid: *pid })
+            .map(|e| e.into_py(py))
+            .unwrap_or_else(|_| py.None()),
+    }
+}
+
+/// Serialize an arbitrary Python object to a Preserves-style byte buffer.
+/// See the module doc comment for the Python<->grammar mapping.
+pub(crate) fn encode_message(py: Python, obj: &PyAny) -> PyResult<Vec<u8>> {
+    let value = py_to_pvalue(py, obj)?;
+    let mut buf = Vec::new();
+    write_value(&mut buf, &value);
+    Ok(buf)
+}
+
+/// Decode a Preserves-style byte buffer into a Python object, raising
+/// `PreservesDecodeError` with a clear message on malformed frames rather
+/// than panicking or returning a confusing Rust error.
+pub(crate) fn decode_message(py: Python, bytes: &[u8]) -> PyResult<PyObject> {
+    let mut cursor = bytes;
+    let value = read_value(&mut cursor)
+        .map_err(|e| PreservesDecodeError::new_err(format!("malformed Preserves frame: {}", e)))?;
+    Ok(pvalue_to_py(py, &value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oversized_sequence_count_is_rejected_not_allocated() {
+        // TAG_SEQUENCE followed by a count claiming ~4 billion elements,
+        // with no actual element bytes behind it.
+        let mut frame = vec![TAG_SEQUENCE];
+        frame.extend_from_slice(&u32::MAX.to_be_bytes());
+        let mut cursor = frame.as_slice();
+        let err = read_value(&mut cursor).unwrap_err();
+        assert!(err.contains("exceeds"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn oversized_dictionary_count_is_rejected_not_allocated() {
+        let mut frame = vec![TAG_DICTIONARY];
+        frame.extend_from_slice(&u32::MAX.to_be_bytes());
+        let mut cursor = frame.as_slice();
+        let err = read_value(&mut cursor).unwrap_err();
+        assert!(err.contains("exceeds"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn sequence_count_that_fits_is_accepted() {
+        let mut frame = vec![TAG_SEQUENCE];
+        frame.extend_from_slice(&2u32.to_be_bytes());
+        frame.push(TAG_BOOL_TRUE);
+        frame.push(TAG_BOOL_FALSE);
+        let mut cursor = frame.as_slice();
+        let value = read_value(&mut cursor).expect("well-formed frame should decode");
+        assert_eq!(
+            value,
+            PValue::Sequence(vec![PValue::Boolean(true), PValue::Boolean(false)])
+        );
+    }
+}
@@ -0,0 +1,135 @@
+// src/py/promise.rs
+//! A single GIL-releasing, dual-awaitable return type for remote/blocking
+//! runtime calls.
+//!
+//! Before this module, `resolve_remote` blocked via `block_in_place` +
+//! `allow_threads`, `resolve_remote_py` returned a bare `future_into_py`
+//! coroutine, and `send_remote`/`monitor_remote`/`is_node_up` were either
+//! fire-and-forget or blocking outright — four different conventions for
+//! "this touches the network, it might take a while." `PyPromise` (modeled
+//! on codemp's `Promise`) replaces all of them: the underlying work is
+//! spawned onto the tokio runtime immediately, with the GIL dropped for the
+//! spawn itself (not just the subsequent `.await`), so a slow remote peer
+//! can never contend the interpreter lock a caller on another thread is
+//! holding. Callers in asyncio code `await` the promise; callers in plain
+//! synchronous code call `.wait(timeout)` instead.
+#![allow(non_local_definitions)]
+
+use pyo3::prelude::*;
+use pyo3_asyncio::tokio::future_into_py;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, Mutex as TokioMutex};
+
+/// The handful of result shapes `PyPromise`'s current callers produce.
+/// Kept as a plain enum (rather than `PyObject`) so the spawned future
+/// doesn't need the GIL to complete — only converting the final value back
+/// to Python does.
+#[derive(Debug)]
+pub(crate) enum PromiseValue {
+    OptU64(Option<u64>),
+    Bool(bool),
+    /// `send_remote`/`monitor_remote`: nothing to report but completion.
+    Unit,
+}
+
+impl PromiseValue {
+    fn into_py(self, py: Python) -> PyObject {
+        match self {
+            PromiseValue::OptU64(v) => v.into_py(py),
+            PromiseValue::Bool(b) => b.into_py(py),
+            PromiseValue::Unit => py.None(),
+        }
+    }
+}
+
+/// Awaitable-or-`.wait()`-able handle to one in-flight promise. Mirrors
+/// `PyJoinHandle`: the result can only be consumed once, through whichever
+/// of `result()`/`wait()`/`__await__` gets there first.
+#[pyclass]
+pub struct PyPromise {
+    rx: Arc<TokioMutex<Option<oneshot::Receiver<PromiseValue>>>>,
+}
+
+fn already_consumed() -> PyErr {
+    pyo3::exceptions::PyRuntimeError::new_err(
+        "Promise already consumed (call wait()/result()/await only once)",
+    )
+}
+
+fn promise_dropped() -> PyErr {
+    pyo3::exceptions::PyRuntimeError::new_err("promise's task was dropped before completing")
+}
+
+impl PyPromise {
+    /// Spawn `fut` onto the tokio runtime and return a promise for its
+    /// eventual result. The spawn itself happens inside `py.allow_threads`
+    /// — dropping the GIL for the duration of the call, not just the
+    /// `.await` that follows — so handing slow work off to tokio can never
+    /// deadlock against another thread that's waiting on the GIL.
+    pub(crate) fn spawn<F>(py: Python, fut: F) -> Self
+    where
+        F: std::future::Future<Output = PromiseValue> + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        py.allow_threads(|| {
+            let task = async move {
+                let _ = tx.send(fut.await);
+            };
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                handle.spawn(task);
+            } else {
+                crate::RUNTIME.spawn(task);
+            }
+        });
+        PyPromise {
+            rx: Arc::new(TokioMutex::new(Some(rx))),
+        }
+    }
+}
+
+#[pymethods]
+impl PyPromise {
+    /// Await this promise from an asyncio loop.
+    fn result<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let rx = self.rx.clone();
+        future_into_py(py, async move {
+            let receiver = rx.lock().await.take().ok_or_else(already_consumed)?;
+            let value = receiver.await.map_err(|_| promise_dropped())?;
+            Python::with_gil(|py| Ok(value.into_py(py)))
+        })
+    }
+
+    /// Block synchronously for the result instead of awaiting it. With
+    /// `timeout` (seconds) given, raises `TimeoutError` if it elapses
+    /// first; `None` (the default) waits indefinitely, same as `.result()`.
+    fn wait(&self, py: Python, timeout: Option<f64>) -> PyResult<PyObject> {
+        let rx = self.rx.clone();
+        let value = py.allow_threads(|| {
+            let op = async {
+                let receiver = rx.lock().await.take().ok_or_else(already_consumed)?;
+                match timeout {
+                    Some(secs) => tokio::time::timeout(Duration::from_secs_f64(secs), receiver)
+                        .await
+                        .map_err(|_| {
+                            pyo3::exceptions::PyTimeoutError::new_err("promise.wait() timed out")
+                        })?
+                        .map_err(|_| promise_dropped()),
+                    None => receiver.await.map_err(|_| promise_dropped()),
+                }
+            };
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                tokio::task::block_in_place(|| handle.block_on(op))
+            } else {
+                crate::RUNTIME.block_on(op)
+            }
+        })?;
+        Ok(value.into_py(py))
+    }
+
+    /// `__await__` support so `await promise` works the same way an
+    /// asyncio future does, by delegating to `result()`'s coroutine.
+    fn __await__<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        self.result(py)?.call_method0("__await__")
+    }
+}
@@ -0,0 +1,139 @@
+// src/py/readiness.rs
+//! Readiness file descriptors for observed mailboxes, so an external event
+//! loop (Python's `asyncio`, or any `select`/`epoll`/`kqueue`-based reactor)
+//! can watch for new messages with `loop.add_reader(...)` instead of the
+//! crate driving its own polling or the caller sleeping between
+//! `get_messages` calls.
+//!
+//! Each observed pid gets one readiness handle on first request, backed by
+//! an `eventfd` on Linux (one syscall, a real counter) or a self-pipe
+//! elsewhere; a background task mirrors `runtime::selective_recv_observed_py`'s
+//! enable-before-check wait on the same observed-message `Notify` and writes
+//! to the fd every time it fires. The caller drains the fd (`drain_ready`)
+//! after servicing it so the next write makes it readable again.
+#![allow(non_local_definitions)]
+
+use dashmap::DashMap;
+use std::os::unix::io::RawFd;
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+struct Readiness {
+    read_fd: RawFd,
+    write_fd: RawFd,
+}
+
+impl Readiness {
+    #[cfg(target_os = "linux")]
+    fn new() -> std::io::Result<Self> {
+        let fd = unsafe { libc::eventfd(0, libc::EFD_NONBLOCK | libc::EFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(Self {
+            read_fd: fd,
+            write_fd: fd,
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn new() -> std::io::Result<Self> {
+        let mut fds = [0i32; 2];
+        if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_NONBLOCK | libc::O_CLOEXEC) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(Self {
+            read_fd: fds[0],
+            write_fd: fds[1],
+        })
+    }
+
+    /// Make the fd readable. Best-effort: a full eventfd counter or pipe
+    /// buffer just means a reader hasn't drained it yet, which is fine —
+    /// it's already readable.
+    fn signal(&self) {
+        #[cfg(target_os = "linux")]
+        {
+            let one: u64 = 1;
+            unsafe {
+                libc::write(self.write_fd, &one as *const u64 as *const libc::c_void, 8);
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            let byte: u8 = 1;
+            unsafe {
+                libc::write(self.write_fd, &byte as *const u8 as *const libc::c_void, 1);
+            }
+        }
+    }
+
+    /// Reset the fd to non-readable after the loop has serviced it.
+    fn drain(&self) {
+        let mut buf = [0u8; 64];
+        loop {
+            let n = unsafe {
+                libc::read(self.read_fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len())
+            };
+            if n <= 0 {
+                break;
+            }
+        }
+    }
+}
+
+impl Drop for Readiness {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.read_fd);
+            if self.write_fd != self.read_fd {
+                libc::close(self.write_fd);
+            }
+        }
+    }
+}
+
+static READINESS: OnceLock<DashMap<u64, Arc<Readiness>>> = OnceLock::new();
+
+fn registry() -> &'static DashMap<u64, Arc<Readiness>> {
+    READINESS.get_or_init(DashMap::new)
+}
+
+/// Return the raw readiness fd for `pid`'s observed mailbox, creating it
+/// (and spawning the background task that keeps it signaled) on first use.
+/// The task runs for the process's lifetime — there's no actor-exit hook in
+/// this tree to tear it down early, so a `mailbox_fd` call on a pid that
+/// later exits just leaves a harmless idle task parked on a `Notify` that
+/// will never fire again.
+pub(crate) fn mailbox_fd(rt: Arc<crate::Runtime>, pid: u64) -> std::io::Result<RawFd> {
+    if let Some(r) = registry().get(&pid) {
+        return Ok(r.read_fd);
+    }
+
+    let readiness = Arc::new(Readiness::new()?);
+    registry().insert(pid, readiness.clone());
+
+    crate::RUNTIME.spawn(async move {
+        loop {
+            // Enable-before-check, same as `selective_recv_observed_py`: a
+            // message pushed between the `enable()` call and the `.await`
+            // still wakes this loop rather than being missed.
+            let notified = rt.observed_notify(pid);
+            let woken = notified.notified();
+            tokio::pin!(woken);
+            woken.as_mut().enable();
+            woken.await;
+            readiness.signal();
+        }
+    });
+
+    Ok(readiness.read_fd)
+}
+
+/// Drain `pid`'s readiness fd after the event loop has serviced it.
+/// A no-op if `mailbox_fd` was never called for `pid`.
+pub(crate) fn drain_ready(pid: u64) {
+    if let Some(r) = registry().get(&pid) {
+        r.drain();
+    }
+}
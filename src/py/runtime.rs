@@ -9,16 +9,87 @@ use std::sync::Arc;
 use std::time::Duration;
 use bytes;
 use tokio::sync::Mutex as TokioMutex;
+use tokio::sync::oneshot;
 
 use crate::Runtime;
 
-use super::pool::{make_release_gil_channel, PoolTask, GIL_WORKER_POOL};
+use super::pool::{make_release_gil_channel, PoolTask, PyJoinHandle, GIL_WORKER_POOL};
 use super::utils::{message_to_py, run_python_matcher};
 use super::mailbox::PyMailbox;
+use super::promise::{PromiseValue, PyPromise};
+#[cfg(feature = "sub_interpreters")]
+use super::subinterp::{BehaviorFactory, SubInterpreterPool, SubTask, SUB_INTERPRETER_POOL};
+
+/// Build a `BehaviorFactory` from `spawn_sub_interpreter_handler`'s/
+/// `hot_swap_sub_interpreters`'s optional arguments: exactly one of
+/// `source` or (`module`, `attr`) must be given.
+#[cfg(feature = "sub_interpreters")]
+fn parse_behavior_factory(
+    source: Option<String>,
+    module: Option<String>,
+    attr: Option<String>,
+) -> PyResult<BehaviorFactory> {
+    match (source, module, attr) {
+        (Some(src), None, None) => Ok(BehaviorFactory::Source(src)),
+        (None, Some(module), Some(attr)) => Ok(BehaviorFactory::Import(module, attr)),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(
+            "pass exactly one of factory_source, or factory_module together with factory_attr",
+        )),
+    }
+}
+
+/// Fetch the process-wide sub-interpreter pool, creating it with `pool_size`
+/// workers running `factory` on first use. Later calls from other actors
+/// reuse the existing pool (and its original size/factory) the same way
+/// `GIL_WORKER_POOL` is shared by every `release_gil` actor that falls back
+/// to it.
+#[cfg(feature = "sub_interpreters")]
+fn get_or_init_sub_interpreter_pool(
+    pool_size: usize,
+    factory: BehaviorFactory,
+) -> PyResult<Arc<SubInterpreterPool>> {
+    if let Some(pool) = SUB_INTERPRETER_POOL.get() {
+        return Ok(pool.clone());
+    }
+    let pool = SubInterpreterPool::new(pool_size, factory)
+        .map_err(pyo3::exceptions::PyRuntimeError::new_err)?;
+    Ok(SUB_INTERPRETER_POOL.get_or_init(|| Arc::new(pool)).clone())
+}
+
+/// Build the restart-intensity/backoff limits shared by `watch`'s and
+/// `supervise_with_factory`'s optional keyword arguments, defaulting to
+/// `RestartLimits::default()` (unlimited restarts, no backoff) field by
+/// field so passing only `max_restarts` doesn't force the caller to also
+/// spell out the rest.
+fn build_restart_limits(
+    max_restarts: Option<u32>,
+    within_secs: Option<u64>,
+    backoff_base_ms: Option<u64>,
+    backoff_cap_ms: Option<u64>,
+) -> crate::supervisor::RestartLimits {
+    let defaults = crate::supervisor::RestartLimits::default();
+    crate::supervisor::RestartLimits {
+        max_restarts: max_restarts.unwrap_or(defaults.max_restarts),
+        within_secs: within_secs.unwrap_or(defaults.within_secs),
+        backoff_base_ms: backoff_base_ms.unwrap_or(defaults.backoff_base_ms),
+        backoff_cap_ms: backoff_cap_ms.unwrap_or(defaults.backoff_cap_ms),
+    }
+}
 
 #[pyclass]
 pub struct PyRuntime {
     pub(crate) inner: std::sync::Arc<Runtime>,
+    /// Peer-node registry backing distributed spawning/supervision; see
+    /// `crate::cluster`. Separate from `inner` since it's addressed by
+    /// short `node_id`s rather than raw socket addresses.
+    cluster: std::sync::Arc<crate::cluster::ClusterRegistry>,
+}
+
+/// Recover the boxed reply sender smuggled through a `SystemMessage::Call`'s
+/// raw pointer, the same trick `HotSwap` uses to carry a non-`Clone` payload
+/// across the `Message` envelope.
+unsafe fn reply_from_ptr(ptr: usize) -> oneshot::Sender<super::pool::CallResult> {
+    *Box::from_raw(ptr as *mut oneshot::Sender<super::pool::CallResult>)
 }
 
 #[pymethods]
@@ -27,9 +98,48 @@ impl PyRuntime {
     fn new() -> Self {
         Self {
             inner: std::sync::Arc::new(crate::Runtime::new()),
+            cluster: std::sync::Arc::new(crate::cluster::ClusterRegistry::new()),
         }
     }
 
+    /// Register (or re-point) a peer node's dial address under a short
+    /// `node_id`, so `supervise_with_factory`/`watch`'s `node` argument and
+    /// `spawn_on_node` can target it without repeating the address.
+    fn register_node(&self, node_id: String, addr: String) -> PyResult<()> {
+        self.cluster.register_node(node_id, addr);
+        Ok(())
+    }
+
+    /// Forget a previously registered peer node.
+    fn unregister_node(&self, node_id: String) -> PyResult<()> {
+        self.cluster.unregister_node(&node_id);
+        Ok(())
+    }
+
+    /// Look up a registered peer node's dial address.
+    fn resolve_node(&self, node_id: String) -> PyResult<Option<String>> {
+        Ok(self.cluster.resolve_node(&node_id))
+    }
+
+    /// Spawn an actor on a registered peer node rather than locally,
+    /// returning its pid. The pid is recorded as living on `node_id`, so
+    /// `is_alive`/`mailbox_size`/`link`/`unlink` know to forward to that
+    /// node instead of consulting the local tables; `watch` does not
+    /// forward.
+    fn spawn_on_node(&self, py: Python, node_id: String, budget: usize) -> PyResult<PyPromise> {
+        let addr = self
+            .cluster
+            .resolve_node(&node_id)
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("unknown node_id"))?;
+        let rt = self.inner.clone();
+        let cluster = self.cluster.clone();
+        Ok(PyPromise::spawn(py, async move {
+            let pid = rt.spawn_remote(addr, budget).await;
+            cluster.set_home(pid, node_id);
+            PromiseValue::OptU64(Some(pid))
+        }))
+    }
+
     // --- Phase 6: Name Registry ---
 
     /// Register a human-readable name for a PID.
@@ -52,6 +162,16 @@ impl PyRuntime {
         Ok(self.inner.resolve(&name))
     }
 
+    /// Resolve `name`, parking until it's registered if it isn't yet.
+    /// Returns a Python Awaitable for use in asyncio loops.
+    fn await_name<'py>(&self, py: Python<'py>, name: String) -> PyResult<&'py PyAny> {
+        let rt = self.inner.clone();
+        future_into_py(py, async move {
+            let pid = rt.await_name(name).await;
+            Ok(pid)
+        })
+    }
+
     /// Alias for resolve (Erlang style).
     fn whereis(&self, name: String) -> PyResult<Option<u64>> {
         Ok(self.inner.resolve(&name))
@@ -108,20 +228,53 @@ impl PyRuntime {
         Ok(self.inner.path_supervisor_children(&path))
     }
 
+    // --- Dataspace (Syndicate-style assert/retract/subscribe) ---
+
+    /// Publish a fact owned by `owner`, returning a handle that can later
+    /// be passed to `retract_fact`. `owner`'s assertions are retracted
+    /// automatically if it exits, the same way `watch_path` subscriptions
+    /// and monitors are torn down on actor exit.
+    fn assert_fact(&self, owner: u64, value: &PyBytes) -> PyResult<u64> {
+        Ok(self
+            .inner
+            .assert_fact(owner, bytes::Bytes::copy_from_slice(value.as_bytes())))
+    }
+
+    /// Withdraw a previously asserted fact. A no-op if `handle` is unknown
+    /// (already retracted, or its owner already exited).
+    fn retract_fact(&self, handle: u64) -> PyResult<()> {
+        self.inner.retract_fact(handle);
+        Ok(())
+    }
+
+    /// Subscribe `subscriber`'s mailbox to every current and future fact
+    /// whose value starts with `pattern`, delivered as `Assert`/`Retract`
+    /// system messages. Once every currently-standing match has been
+    /// delivered, a `Synced` message follows, so `subscriber` can tell it
+    /// has caught up on the backlog instead of racing it against live
+    /// updates. Returns a subscription handle for `unsubscribe_dataspace`.
+    fn subscribe_dataspace(&self, subscriber: u64, pattern: &PyBytes) -> PyResult<u64> {
+        Ok(self
+            .inner
+            .subscribe_dataspace(subscriber, bytes::Bytes::copy_from_slice(pattern.as_bytes())))
+    }
+
+    /// Cancel a `subscribe_dataspace` subscription.
+    fn unsubscribe_dataspace(&self, subscription: u64) -> PyResult<()> {
+        self.inner.unsubscribe_dataspace(subscription);
+        Ok(())
+    }
+
     // --- End Registry ---
 
-    /// Phase 7: Resolve a name on a remote node (Synchronous/Blocking).
-    /// Detects if an active runtime exists. If so, uses block_in_place to avoid panics.
-    fn resolve_remote(&self, py: Python, addr: String, name: String) -> PyResult<Option<u64>> {
+    /// Phase 7: Resolve a name on a remote node. Returns a `PyPromise`: a
+    /// synchronous caller calls `.wait(timeout)`, an asyncio caller
+    /// `await`s it directly. The lookup is spawned onto tokio with the GIL
+    /// dropped, so a slow/unreachable peer can't hold up the interpreter.
+    fn resolve_remote(&self, py: Python, addr: String, name: String) -> PyPromise {
         let rt = self.inner.clone();
-        py.allow_threads(|| {
-            if let Ok(handle) = tokio::runtime::Handle::try_current() {
-                Ok(tokio::task::block_in_place(|| {
-                    handle.block_on(rt.resolve_remote_async(addr, name))
-                }))
-            } else {
-                Ok(crate::RUNTIME.block_on(rt.resolve_remote_async(addr, name)))
-            }
+        PyPromise::spawn(py, async move {
+            PromiseValue::OptU64(rt.resolve_remote_async(addr, name).await)
         })
     }
 
@@ -158,36 +311,180 @@ impl PyRuntime {
         Ok(())
     }
 
-    /// Phase 5: Send a binary payload to a PID on a remote node.
-    fn send_remote(&self, addr: String, pid: u64, data: &PyBytes) -> PyResult<()> {
+    /// Bound the release_gil worker queue (dedicated thread or shared pool)
+    /// to `capacity` pending tasks, with `policy` selecting the behavior
+    /// once it's full: `"block"` (default) suspends the submitting actor
+    /// until a slot frees up, `"drop_newest"` discards the incoming task
+    /// immediately, and `"err"` does the same but also logs the drop since a
+    /// fire-and-forget `send` has no `call`-style `JoinHandle` to fail
+    /// instead. `capacity` of `0` means unbounded, matching the pre-existing
+    /// behavior.
+    fn set_release_gil_queue_policy(&self, capacity: usize, policy: Option<String>) -> PyResult<()> {
+        self.inner
+            .set_release_gil_queue_policy(capacity, policy.unwrap_or_else(|| "block".to_string()));
+        Ok(())
+    }
+
+    /// Route uncaught exceptions from `release_gil` actor callbacks to a
+    /// supervisor instead of letting them vanish into stderr. Give
+    /// `supervisor_pid` to deliver each error as a MessagePack `{kind,
+    /// message, traceback, behavior_id}` map (decode with plain
+    /// `mailbox.recv_obj`); give `callback` to invoke it directly with
+    /// `(kind, message, traceback, behavior_id)` instead. Passing neither
+    /// clears the sink and restores the original eprintln-only behavior;
+    /// passing both is an error. Process-wide, like the GIL worker pool
+    /// itself: every `release_gil` actor shares one sink.
+    fn set_actor_error_sink(
+        &self,
+        supervisor_pid: Option<u64>,
+        callback: Option<PyObject>,
+    ) -> PyResult<()> {
+        let sink = match (supervisor_pid, callback) {
+            (Some(pid), None) => Some(super::pool::ErrorSink::Supervisor {
+                rt: self.inner.clone(),
+                pid,
+            }),
+            (None, Some(cb)) => Some(super::pool::ErrorSink::Callback(Arc::new(cb))),
+            (None, None) => None,
+            (Some(_), Some(_)) => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "pass at most one of supervisor_pid, callback",
+                ))
+            }
+        };
+        super::pool::set_actor_error_sink(sink);
+        Ok(())
+    }
+
+    /// `(queued, capacity, total_dropped)` for the shared GIL worker pool, or
+    /// `None` if release_gil has never fallen back to it. `capacity` is
+    /// `None` when the shared pool's queue is unbounded; `total_dropped`
+    /// counts drops across every bounded release_gil queue, dedicated or
+    /// shared, since process start.
+    fn release_gil_queue_stats(&self) -> PyResult<Option<(usize, Option<usize>, u64)>> {
+        Ok(super::pool::queue_stats())
+    }
+
+    /// Phase 5: Send a binary payload to a PID on a remote node. Returns a
+    /// `PyPromise` like `resolve_remote`/`is_node_up`, for a consistent
+    /// `.wait()`/`await` surface across every remote-facing call even
+    /// though this one completes immediately.
+    fn send_remote(&self, py: Python, addr: String, pid: u64, data: &PyBytes) -> PyPromise {
+        let rt = self.inner.clone();
+        let bytes = bytes::Bytes::copy_from_slice(data.as_bytes());
+        PyPromise::spawn(py, async move {
+            rt.send_remote(addr, pid, bytes);
+            PromiseValue::Unit
+        })
+    }
+
+    /// Phase 7+: send `data` to `pid` on the peer at `addr` and await its
+    /// typed reply over the type-2/3 RPC frames (see
+    /// `network::NetworkManager::call_remote`). Returns a plain awaitable
+    /// rather than a `PyPromise`: unlike `send_remote`/`resolve_remote`, a
+    /// remote call can genuinely fail (timeout, dead peer, no such pid) and
+    /// needs to raise that back to the caller instead of resolving to
+    /// `None`/`False`.
+    fn call_remote<'py>(
+        &self,
+        py: Python<'py>,
+        addr: String,
+        pid: u64,
+        data: &PyBytes,
+        timeout_secs: f64,
+    ) -> PyResult<&'py PyAny> {
+        let rt = self.inner.clone();
         let bytes = bytes::Bytes::copy_from_slice(data.as_bytes());
-        self.inner.send_remote(addr, pid, bytes);
+        future_into_py(py, async move {
+            let reply = rt
+                .call_remote(&addr, pid, bytes, Duration::from_secs_f64(timeout_secs))
+                .await
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+            Ok(Python::with_gil(|py| PyBytes::new(py, &reply).into_py(py)))
+        })
+    }
+
+    /// Monitor a local actor: when `pid` exits, a `Down { handle, pid,
+    /// reason }` system message is delivered into `watcher`'s own mailbox,
+    /// giving Python OTP-style supervision instead of polling `is_alive`.
+    /// Returns a handle identifying this monitor, for `demonitor`.
+    fn monitor(&self, watcher: u64, pid: u64) -> PyResult<u64> {
+        Ok(self.inner.monitor(watcher, pid))
+    }
+
+    /// Cancel a monitor started by `monitor`/`monitor_remote`.
+    fn demonitor(&self, handle: u64) -> PyResult<()> {
+        self.inner.demonitor(handle);
         Ok(())
     }
 
-    /// Phase 5: Monitor a remote PID.
-    fn monitor_remote(&self, addr: String, pid: u64) -> PyResult<()> {
-        self.inner.monitor_remote(addr, pid);
+    /// Phase 5: Monitor a remote PID. When `interval_ms`/`timeout_ms` are
+    /// given, an active heartbeat is layered on top of the connection
+    /// watch: `Ping`s are sent at `interval_ms` and a missing `Pong` within
+    /// `timeout_ms` for `max_missed` consecutive attempts synthesizes a
+    /// `Timeout` exit instead of waiting for the socket to drop. Either
+    /// way, a `Down` message lands in `watcher`'s mailbox on exit, same as
+    /// `monitor`. Returns a handle for `demonitor`.
+    fn monitor_remote(
+        &self,
+        watcher: u64,
+        addr: String,
+        pid: u64,
+        interval_ms: Option<u64>,
+        timeout_ms: Option<u64>,
+        max_missed: Option<u32>,
+    ) -> PyResult<u64> {
+        Ok(match (interval_ms, timeout_ms) {
+            (Some(interval), Some(timeout)) => self.inner.monitor_remote_heartbeat(
+                watcher,
+                addr,
+                pid,
+                std::time::Duration::from_millis(interval),
+                std::time::Duration::from_millis(timeout),
+                max_missed.unwrap_or(3),
+            ),
+            _ => self.inner.monitor_remote(watcher, addr, pid),
+        })
+    }
+
+    /// Set whether `pid` traps EXIT signals from its links. While
+    /// trapping, an abnormal exit from a linked peer is delivered as an
+    /// ordinary `SystemMessage::Exit` message instead of cascading a kill
+    /// signal to `pid` itself (Erlang's `process_flag(trap_exit, ...)`).
+    fn set_trap_exit(&self, pid: u64, trap: bool) -> PyResult<()> {
+        self.inner.set_trap_exit(pid, trap);
         Ok(())
     }
 
-    /// Quick network probe to check if a node is reachable.
-    /// Returns a boolean directly from the future to avoid type inference issues.
-    fn is_node_up(&self, py: Python, addr: String) -> PyResult<bool> {
-        let fut = async {
-            match tokio::net::TcpStream::connect(&addr).await {
-                Ok(_) => true,
-                Err(_) => false,
-            }
-        };
+    /// Supervise a local actor with an active heartbeat: every
+    /// `interval_ms` a `Ping` is sent to `pid` and a reply `Pong` is
+    /// expected within `timeout_ms`. After `max_missed` consecutive
+    /// unanswered pings, an `ExitInfo { from: pid, reason:
+    /// ExitReason::Timeout, .. }` is delivered to actors linked to or
+    /// monitoring `pid`, the same as it would be for a normal crash.
+    fn monitor_heartbeat(
+        &self,
+        pid: u64,
+        interval_ms: u64,
+        timeout_ms: u64,
+        max_missed: Option<u32>,
+    ) -> PyResult<()> {
+        self.inner.monitor_heartbeat(
+            pid,
+            std::time::Duration::from_millis(interval_ms),
+            std::time::Duration::from_millis(timeout_ms),
+            max_missed.unwrap_or(3),
+        );
+        Ok(())
+    }
 
-        py.allow_threads(|| {
-            if let Ok(handle) = tokio::runtime::Handle::try_current() {
-                // Must use block_in_place to prevent "runtime within runtime" panic
-                Ok(tokio::task::block_in_place(|| handle.block_on(fut)))
-            } else {
-                Ok(crate::RUNTIME.block_on(fut))
-            }
+    /// Quick network probe to check if a node is reachable. Returns a
+    /// `PyPromise` resolving to a bool, same as the rest of the
+    /// remote-facing API.
+    fn is_node_up(&self, py: Python, addr: String) -> PyPromise {
+        PyPromise::spawn(py, async move {
+            let up = tokio::net::TcpStream::connect(&addr).await.is_ok();
+            PromiseValue::Bool(up)
         })
     }
 
@@ -222,6 +519,112 @@ impl PyRuntime {
         Ok(())
     }
 
+    /// Drain and stop the shared GIL worker pool used by `release_gil`
+    /// actors that fell back to it. The pool is process-wide, not tied to
+    /// this `PyRuntime`, so this is a no-op if no pooled actor has ever
+    /// needed it. With `wait=True` (the default) this blocks until every
+    /// worker has finished its queued tasks and exited (or
+    /// `SHUTDOWN_JOIN_TIMEOUT` elapses, whichever comes first). The join
+    /// runs with the GIL released — a queued worker task may need to
+    /// reacquire it to run, which would otherwise deadlock against this
+    /// call holding it while blocked in `join()`.
+    fn shutdown_gil_pool(&self, py: Python, wait: Option<bool>) -> PyResult<()> {
+        py.allow_threads(|| super::pool::shutdown_gil_pool(wait.unwrap_or(true)));
+        Ok(())
+    }
+
+    /// `True` if the running CPython is new enough (3.12+, exposing
+    /// `Py_NewInterpreterFromConfig`/`PyInterpreterConfig_OWN_GIL`) for
+    /// `spawn_sub_interpreter_handler` to work. Always `False` when the
+    /// crate was built without the `sub_interpreters` feature.
+    #[cfg(feature = "sub_interpreters")]
+    fn sub_interpreters_supported(&self) -> bool {
+        super::subinterp::supported()
+    }
+
+    /// Spawn an actor whose Python callback runs across a pool of `pool_size`
+    /// per-worker CPython sub-interpreters, each with its own GIL, instead of
+    /// behind the single process-wide GIL `spawn_py_handler` shares with
+    /// every other actor — giving pure-Python actors true multi-core
+    /// parallelism. Exactly one of `factory_source` or (`factory_module`,
+    /// `factory_attr`) must be given: `factory_source` is Python source
+    /// `exec`'d in each sub-interpreter to define a top-level
+    /// `behavior(msg: bytes) -> bytes` function, while `factory_module`/
+    /// `factory_attr` import an existing callable instead. A `PyObject`
+    /// can't be shared across sub-interpreters (see `subinterp` module
+    /// docs), which is why this takes a factory instead of a callable.
+    ///
+    /// The pool is created lazily on first call and shared by every
+    /// sub-interpreter actor in the process (`pool_size` and the factory
+    /// only take effect the first time); only the raw `bytes -> bytes`
+    /// wire contract is supported (no `send_obj`/`recv_obj`, no arbitrary
+    /// `call` return values).
+    #[cfg(feature = "sub_interpreters")]
+    fn spawn_sub_interpreter_handler(
+        &self,
+        budget: usize,
+        pool_size: usize,
+        factory_source: Option<String>,
+        factory_module: Option<String>,
+        factory_attr: Option<String>,
+    ) -> PyResult<u64> {
+        let factory = parse_behavior_factory(factory_source, factory_module, factory_attr)?;
+        let pool = get_or_init_sub_interpreter_pool(pool_size, factory)?;
+
+        let handler = move |msg: crate::mailbox::Message| {
+            let pool = pool.clone();
+            async move {
+                match msg {
+                    crate::mailbox::Message::User(bytes) => {
+                        pool.submit(SubTask::Execute { bytes, reply: None });
+                    }
+                    crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(
+                        bytes,
+                        ptr,
+                    )) => {
+                        pool.submit(SubTask::Execute {
+                            bytes,
+                            reply: Some(unsafe { reply_from_ptr(ptr) }),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        Ok(self.inner.spawn_handler_with_budget(handler, budget))
+    }
+
+    /// Re-run a (possibly new) behavior factory in every sub-interpreter
+    /// worker, replacing each worker's cached behavior. Pool-wide rather
+    /// than scoped to one actor: every actor sharing the pool picks up the
+    /// new behavior, since the pool (not any one actor) owns the workers.
+    /// A no-op if no sub-interpreter actor has been spawned yet.
+    #[cfg(feature = "sub_interpreters")]
+    fn hot_swap_sub_interpreters(
+        &self,
+        factory_source: Option<String>,
+        factory_module: Option<String>,
+        factory_attr: Option<String>,
+    ) -> PyResult<()> {
+        let factory = parse_behavior_factory(factory_source, factory_module, factory_attr)?;
+        if let Some(pool) = SUB_INTERPRETER_POOL.get() {
+            pool.broadcast_hot_swap(factory);
+        }
+        Ok(())
+    }
+
+    /// Drain and stop the shared sub-interpreter pool, mirroring
+    /// `shutdown_gil_pool`. No-op if no sub-interpreter actor has been
+    /// spawned.
+    #[cfg(feature = "sub_interpreters")]
+    fn shutdown_sub_interpreter_pool(&self, wait: Option<bool>) -> PyResult<()> {
+        if let Some(pool) = SUB_INTERPRETER_POOL.get() {
+            pool.shutdown(wait.unwrap_or(true));
+        }
+        Ok(())
+    }
+
     fn spawn_observed_handler(&self, budget: usize) -> u64 {
         self.inner.spawn_observed_handler(budget)
     }
@@ -271,15 +674,24 @@ impl PyRuntime {
                             let task = PoolTask::Execute {
                                 behavior: b.clone(),
                                 bytes: bytes.clone(),
+                                reply: None,
+                            };
+                            tx.submit(task).await;
+                        }
+                        crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, ptr)) => {
+                            let task = PoolTask::Execute {
+                                behavior: b.clone(),
+                                bytes,
+                                reply: Some(unsafe { reply_from_ptr(ptr) }),
                             };
-                            let _ = tx.send(task);
+                            tx.submit(task).await;
                         }
                         crate::mailbox::Message::System(crate::mailbox::SystemMessage::HotSwap(ptr)) => {
                             let task = PoolTask::HotSwap {
                                 behavior: b.clone(),
                                 ptr,
                             };
-                            let _ = tx.send(task);
+                            tx.submit(task).await;
                         }
                         _ => {}
                     }
@@ -291,8 +703,9 @@ impl PyRuntime {
                                 let task = PoolTask::Execute {
                                     behavior: b.clone(),
                                     bytes: bytes.clone(),
+                                    reply: None,
                                 };
-                                let _ = pool.sender.send(task);
+                                pool.sender.submit(task).await;
                             } else {
                                 Python::with_gil(|py| {
                                     let guard = b.read();
@@ -305,13 +718,42 @@ impl PyRuntime {
                                 });
                             }
                         }
+                        crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, ptr)) => {
+                            let reply = unsafe { reply_from_ptr(ptr) };
+                            if let Some(pool) = GIL_WORKER_POOL.get() {
+                                let task = PoolTask::Execute {
+                                    behavior: b.clone(),
+                                    bytes,
+                                    reply: Some(reply),
+                                };
+                                pool.sender.submit(task).await;
+                            } else {
+                                Python::with_gil(|py| {
+                                    let guard = b.read();
+                                    let cb = guard.as_ref(py);
+                                    let pybytes = PyBytes::new(py, &bytes);
+                                    match cb.call1((pybytes,)) {
+                                        Ok(ret) => {
+                                            let result = super::codec::py_to_msgpack(py, ret)
+                                                .map_err(|e| e.to_string());
+                                            let _ = reply.send(result);
+                                        }
+                                        Err(e) => {
+                                            eprintln!("[Iris] Python actor exception: {}", e);
+                                            e.print(py);
+                                            let _ = reply.send(Err(e.to_string()));
+                                        }
+                                    }
+                                });
+                            }
+                        }
                         crate::mailbox::Message::System(crate::mailbox::SystemMessage::HotSwap(ptr)) => {
                             if let Some(pool) = GIL_WORKER_POOL.get() {
                                 let task = PoolTask::HotSwap {
                                     behavior: b.clone(),
                                     ptr,
                                 };
-                                let _ = pool.sender.send(task);
+                                pool.sender.submit(task).await;
                             } else {
                                 Python::with_gil(|py| unsafe {
                                     let new_obj =
@@ -343,6 +785,26 @@ impl PyRuntime {
                                 }
                             });
                         }
+                        crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, ptr)) => {
+                            let reply = unsafe { reply_from_ptr(ptr) };
+                            Python::with_gil(|py| {
+                                let guard = b.read();
+                                let cb = guard.as_ref(py);
+                                let pybytes = PyBytes::new(py, &bytes);
+                                match cb.call1((pybytes,)) {
+                                    Ok(ret) => {
+                                        let result = super::codec::py_to_msgpack(py, ret)
+                                            .map_err(|e| e.to_string());
+                                        let _ = reply.send(result);
+                                    }
+                                    Err(e) => {
+                                        eprintln!("[Iris] Python actor exception: {}", e);
+                                        e.print(py);
+                                        let _ = reply.send(Err(e.to_string()));
+                                    }
+                                }
+                            });
+                        }
                         crate::mailbox::Message::System(crate::mailbox::SystemMessage::Exit(_info)) => {
                             // nothing special
                         }
@@ -380,12 +842,20 @@ impl PyRuntime {
                     if let Some(tx) = &maybe_tx {
                         match msg {
                             crate::mailbox::Message::User(bytes) => {
-                                let task = PoolTask::Execute { behavior: behavior.clone(), bytes: bytes.clone() };
-                                let _ = tx.send(task);
+                                let task = PoolTask::Execute { behavior: behavior.clone(), bytes: bytes.clone(), reply: None };
+                                tx.submit(task).await;
+                            }
+                            crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, ptr)) => {
+                                let task = PoolTask::Execute {
+                                    behavior: behavior.clone(),
+                                    bytes,
+                                    reply: Some(unsafe { reply_from_ptr(ptr) }),
+                                };
+                                tx.submit(task).await;
                             }
                             crate::mailbox::Message::System(crate::mailbox::SystemMessage::HotSwap(ptr)) => {
                                 let task = PoolTask::HotSwap { behavior: behavior.clone(), ptr };
-                                let _ = tx.send(task);
+                                tx.submit(task).await;
                             }
                             _ => {}
                         }
@@ -412,6 +882,26 @@ impl PyRuntime {
                                     }
                                 });
                             }
+                            crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, ptr)) => {
+                                let reply = unsafe { reply_from_ptr(ptr) };
+                                Python::with_gil(|py| {
+                                    let guard = behavior.read();
+                                    let cb = guard.as_ref(py);
+                                    let pybytes = PyBytes::new(py, &bytes);
+                                    match cb.call1((pybytes,)) {
+                                        Ok(ret) => {
+                                            let result = super::codec::py_to_msgpack(py, ret)
+                                                .map_err(|e| e.to_string());
+                                            let _ = reply.send(result);
+                                        }
+                                        Err(e) => {
+                                            eprintln!("[Iris] Python actor exception: {}", e);
+                                            e.print(py);
+                                            let _ = reply.send(Err(e.to_string()));
+                                        }
+                                    }
+                                });
+                            }
                             _ => {}
                         }
                     }
@@ -457,12 +947,20 @@ impl PyRuntime {
                     // blocking GIL thread path
                     match msg {
                         crate::mailbox::Message::User(bytes) => {
-                            let task = PoolTask::Execute { behavior: behavior.clone(), bytes: bytes.clone() };
-                            let _ = tx.send(task);
+                            let task = PoolTask::Execute { behavior: behavior.clone(), bytes: bytes.clone(), reply: None };
+                            tx.submit(task).await;
+                        }
+                        crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, ptr)) => {
+                            let task = PoolTask::Execute {
+                                behavior: behavior.clone(),
+                                bytes,
+                                reply: Some(unsafe { reply_from_ptr(ptr) }),
+                            };
+                            tx.submit(task).await;
                         }
                         crate::mailbox::Message::System(crate::mailbox::SystemMessage::HotSwap(ptr)) => {
                             let task = PoolTask::HotSwap { behavior: behavior.clone(), ptr };
-                            let _ = tx.send(task);
+                            tx.submit(task).await;
                         }
                         _ => {}
                     }
@@ -488,6 +986,26 @@ impl PyRuntime {
                                 }
                             });
                         }
+                        crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, ptr)) => {
+                            let reply = unsafe { reply_from_ptr(ptr) };
+                            Python::with_gil(|py| {
+                                let guard = behavior.read();
+                                let cb = guard.as_ref(py);
+                                let pybytes = PyBytes::new(py, &bytes);
+                                match cb.call1((pybytes,)) {
+                                    Ok(ret) => {
+                                        let result = super::codec::py_to_msgpack(py, ret)
+                                            .map_err(|e| e.to_string());
+                                        let _ = reply.send(result);
+                                    }
+                                    Err(e) => {
+                                        eprintln!("[Iris] Python actor exception: {}", e);
+                                        e.print(py);
+                                        let _ = reply.send(Err(e.to_string()));
+                                    }
+                                }
+                            });
+                        }
                         _ => {}
                     }
                 }
@@ -534,6 +1052,50 @@ impl PyRuntime {
         Ok(pid)
     }
 
+    /// Spawn an actor whose callback is an `async def` coroutine function
+    /// rather than a blocking callable. Each message calls `coro_fn(bytes)`
+    /// and awaits the resulting coroutine on a shared `LocalSet` worker
+    /// pool, so the actor can `await` I/O without burning an OS thread the
+    /// way `spawn_with_mailbox` does.
+    fn spawn_py_async_handler(&self, coro_fn: PyObject, budget: usize) -> PyResult<u64> {
+        let pid = self.inner.spawn_actor_with_budget(
+            move |rx| async move {
+                let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+                super::coro::local_set_pool().dispatch(super::coro::LocalSetTask::Actor {
+                    coro_fn,
+                    rx,
+                    done: done_tx,
+                });
+                let _ = done_rx.await;
+            },
+            budget,
+        );
+
+        Ok(pid)
+    }
+
+    /// Set the shared batching interval (microseconds) used by every
+    /// `spawn_py_handler_throttled` actor. See `throttle` module docs.
+    fn set_throttle(&self, interval_us: u64) -> PyResult<()> {
+        super::throttle::set_throttle(interval_us);
+        Ok(())
+    }
+
+    /// Like `spawn_py_handler`, but batches messages instead of delivering
+    /// them one at a time: the callback is invoked with a single `list` of
+    /// `bytes` containing everything buffered since the last tick of the
+    /// shared throttle interval (see `set_throttle`), amortizing the GIL
+    /// acquisition across many messages. Intended for workloads with many
+    /// low-traffic actors, not latency-sensitive ones.
+    fn spawn_py_handler_throttled(&self, py_callable: PyObject, budget: usize) -> PyResult<u64> {
+        let behavior = Arc::new(parking_lot::RwLock::new(py_callable));
+        let pid = self.inner.spawn_actor_with_budget(
+            move |rx| super::throttle::run_throttled_actor(behavior, rx),
+            budget,
+        );
+        Ok(pid)
+    }
+
     /// Spawn a child actor that uses a blocking Python mailbox loop.
     fn spawn_child_with_mailbox(&self, parent: u64, py_callable: PyObject, budget: usize) -> PyResult<u64> {
         let pid = self.inner.spawn_child_with_budget(parent, move |rx| async move {
@@ -568,6 +1130,82 @@ impl PyRuntime {
         .is_ok())
     }
 
+    /// Send `data` to `pid` and return a `JoinHandle` for the actor's
+    /// return value, instead of firing and forgetting like `send`. Only
+    /// actors spawned with `release_gil=True` (dedicated thread or shared
+    /// pool) execute the callback off the mailbox's own task, so this works
+    /// for any pooled actor; an inline actor runs the callback on its own
+    /// mailbox task and replies from there. Either way `call` returns as
+    /// soon as the message is queued.
+    fn call(&self, pid: u64, data: &PyBytes) -> PyResult<PyJoinHandle> {
+        let bytes = bytes::Bytes::copy_from_slice(data.as_bytes());
+        let (tx, rx) = oneshot::channel();
+        let reply_ptr = Box::into_raw(Box::new(tx)) as usize;
+        let msg = crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, reply_ptr));
+        if self.inner.send(pid, msg).is_err() {
+            // Actor is gone; reclaim the boxed sender so it isn't leaked,
+            // then let `rx` observe the resulting `Closed` error.
+            let _ = unsafe { reply_from_ptr(reply_ptr) };
+        }
+        Ok(PyJoinHandle::new(rx))
+    }
+
+    /// Like `call`, but returns a `PyRustPromise` — a blocking `pyawait()`/
+    /// non-blocking `poll()` handle that is also directly `await`-able from
+    /// asyncio (see `wrappers::PyRustPromise`) — instead of `PyJoinHandle`'s
+    /// `async`-only `result()`, for synchronous Python callers that want a
+    /// real completion signal instead of `sleep` + polling `get_messages`,
+    /// while async callers still get a cooperative awaitable.
+    fn ask(&self, pid: u64, data: &PyBytes) -> PyResult<super::wrappers::PyRustPromise> {
+        let bytes = bytes::Bytes::copy_from_slice(data.as_bytes());
+        let (tx, rx) = oneshot::channel();
+        let reply_ptr = Box::into_raw(Box::new(tx)) as usize;
+        let msg = crate::mailbox::Message::System(crate::mailbox::SystemMessage::Call(bytes, reply_ptr));
+        if self.inner.send(pid, msg).is_err() {
+            let _ = unsafe { reply_from_ptr(reply_ptr) };
+        }
+        Ok(super::wrappers::PyRustPromise::new(rx))
+    }
+
+    /// Serialize `obj` to MessagePack and send it as a `User` message.
+    /// Supports None/bool/int/float/str/bytes/list/tuple/dict; raises
+    /// `TypeError` for anything else. Pairs with `PyMailbox.recv_obj` on the
+    /// receiving end, which decodes the frame back into a Python object.
+    fn send_obj(&self, py: Python, pid: u64, obj: &PyAny) -> PyResult<bool> {
+        let bytes = super::codec::py_to_msgpack(py, obj)?;
+        let msg = bytes::Bytes::from(bytes);
+        Ok(self
+        .inner
+        .send(pid, crate::mailbox::Message::User(msg))
+        .is_ok())
+    }
+
+    /// Serialize `obj` to the Preserves-style wire format (see
+    /// `crate::py::preserves`), rather than sending it anywhere: a stable,
+    /// language-neutral encoding instead of an ad-hoc PyObject conversion.
+    /// The network layer does not apply it itself; `send_remote`/
+    /// `call_remote` carry whatever bytes the caller passes. Pairs with
+    /// `decode_message` on the receiving end.
+    fn encode_message<'py>(&self, py: Python<'py>, obj: &PyAny) -> PyResult<&'py PyBytes> {
+        let bytes = super::preserves::encode_message(py, obj)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Decode a Preserves-style frame produced by `encode_message` back
+    /// into a Python object. Raises `PreservesDecodeError` on a malformed
+    /// or truncated frame.
+    fn decode_message(&self, py: Python, bytes: &PyBytes) -> PyResult<PyObject> {
+        super::preserves::decode_message(py, bytes.as_bytes())
+    }
+
+    /// Send a binary payload stamped with a correlation `tag` so the receiver
+    /// can pull it out of order with `mailbox.recv_tagged([tag])`. Tag `0` is
+    /// reserved for untagged sends; pick a nonzero tag per request.
+    fn send_tagged(&self, pid: u64, tag: u64, data: &PyBytes) -> PyResult<bool> {
+        let bytes = bytes::Bytes::copy_from_slice(data.as_bytes());
+        Ok(self.inner.send_tagged(pid, tag, bytes).is_ok())
+    }
+
     /// Schedule a one-shot send from Python. Returns a numeric timer id.
     fn send_after(&self, pid: u64, delay_ms: u64, data: &PyBytes) -> PyResult<u64> {
         let msg = bytes::Bytes::copy_from_slice(data.as_bytes());
@@ -591,7 +1229,11 @@ impl PyRuntime {
         Ok(self.inner.cancel_timer(timer_id))
     }
 
-    /// Await selectively on observed messages for `pid` using a Python callable.
+    /// Await selectively on observed messages for `pid` using a Python
+    /// callable. Driven by `pid`'s observed-message `Notify` (woken
+    /// whenever the runtime pushes a new observed message) rather than a
+    /// fixed polling interval, so a match is seen as soon as it arrives
+    /// and an idle waiter burns no CPU between messages.
     fn selective_recv_observed_py<'py>(
         &self,
         py: Python<'py>,
@@ -603,6 +1245,16 @@ impl PyRuntime {
         future_into_py(py, async move {
             let op = async {
                 loop {
+                    // Register interest in the next notification *before*
+                    // checking, so a message pushed between the check and
+                    // the `.await` below still wakes this waiter instead
+                    // of being missed (same pattern `mailbox`'s bounded
+                    // queue uses around `Notify`).
+                    let notified = rt.observed_notify(pid);
+                    let woken = notified.notified();
+                    tokio::pin!(woken);
+                    woken.as_mut().enable();
+
                     // Attempt to take a matching observed message atomically.
                     if let Some(m) = rt.take_observed_message_matching(pid, |msg| {
                         // Call into Python matcher to decide.
@@ -612,8 +1264,9 @@ impl PyRuntime {
                         return Python::with_gil(|py| message_to_py(py, m));
                     }
 
-                    // Not found yet — yield a bit and try again.
-                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    // Nothing matched yet; sleep until the next push
+                    // instead of re-scanning on a timer.
+                    woken.await;
                 }
             };
 
@@ -638,12 +1291,45 @@ impl PyRuntime {
         }
     }
 
+    /// Raw readiness file descriptor for `pid`'s observed mailbox, for
+    /// registering with an external event loop (`loop.add_reader(...)`) so
+    /// the caller only calls `get_messages`/selective receive when the fd
+    /// says something is actually pending, instead of polling on a timer.
+    /// See `crate::py::readiness`.
+    fn mailbox_fd(&self, pid: u64) -> PyResult<i32> {
+        super::readiness::mailbox_fd(self.inner.clone(), pid)
+            .map_err(|e| pyo3::exceptions::PyOSError::new_err(e.to_string()))
+    }
+
+    /// Reset `pid`'s readiness fd after the event loop has serviced it, so
+    /// it only becomes readable again once another message arrives.
+    fn drain_ready(&self, pid: u64) -> PyResult<()> {
+        super::readiness::drain_ready(pid);
+        Ok(())
+    }
+
+    /// Liveness check. When `pid` was spawned via `spawn_on_node` and is
+    /// still recorded as living on a peer, this queries that node instead
+    /// of the local actor table.
     fn is_alive(&self, pid: u64) -> bool {
-        self.inner.is_alive(pid)
+        match self.cluster.home_of(pid) {
+            Some(node_id) => match self.cluster.resolve_node(&node_id) {
+                Some(addr) => self.inner.is_alive_remote(&addr, pid),
+                None => false,
+            },
+            None => self.inner.is_alive(pid),
+        }
     }
 
+    /// Like `is_alive`, forwarded to `pid`'s home node when it has one.
     fn mailbox_size(&self, pid: u64) -> PyResult<Option<usize>> {
-        Ok(self.inner.mailbox_size(pid))
+        match self.cluster.home_of(pid) {
+            Some(node_id) => match self.cluster.resolve_node(&node_id) {
+                Some(addr) => Ok(self.inner.mailbox_size_remote(&addr, pid)),
+                None => Ok(None),
+            },
+            None => Ok(self.inner.mailbox_size(pid)),
+        }
     }
 
     fn children_count(&self) -> usize {
@@ -654,17 +1340,88 @@ impl PyRuntime {
         self.inner.supervisor().child_pids()
     }
 
+    /// Link `a` and `b`. When either lives on a peer node (per
+    /// `spawn_on_node`), the link is forwarded over the network so an
+    /// abnormal exit on one side still reaches the other. When `a` and `b`
+    /// are homed on two *different* peers, both nodes need to hear about
+    /// it — each only monitors its own local half of the link.
     fn link(&self, a: u64, b: u64) -> PyResult<()> {
-        self.inner.link(a, b);
+        match (self.cluster.home_of(a), self.cluster.home_of(b)) {
+            (None, None) => self.inner.link(a, b),
+            (Some(node), None) | (None, Some(node)) => {
+                if let Some(addr) = self.cluster.resolve_node(&node) {
+                    self.inner.link_remote(&addr, a, b);
+                }
+            }
+            (Some(node_a), Some(node_b)) if node_a == node_b => {
+                if let Some(addr) = self.cluster.resolve_node(&node_a) {
+                    self.inner.link_remote(&addr, a, b);
+                }
+            }
+            (Some(node_a), Some(node_b)) => {
+                if let Some(addr) = self.cluster.resolve_node(&node_a) {
+                    self.inner.link_remote(&addr, a, b);
+                }
+                if let Some(addr) = self.cluster.resolve_node(&node_b) {
+                    self.inner.link_remote(&addr, a, b);
+                }
+            }
+        }
         Ok(())
     }
 
+    /// Unlink `a` and `b`, forwarded the same way `link` is — to both of
+    /// their home nodes when they differ, so neither side is left still
+    /// watching the other.
     fn unlink(&self, a: u64, b: u64) -> PyResult<()> {
-        self.inner.unlink(a, b);
+        match (self.cluster.home_of(a), self.cluster.home_of(b)) {
+            (None, None) => self.inner.unlink(a, b),
+            (Some(node), None) | (None, Some(node)) => {
+                if let Some(addr) = self.cluster.resolve_node(&node) {
+                    self.inner.unlink_remote(&addr, a, b);
+                }
+            }
+            (Some(node_a), Some(node_b)) if node_a == node_b => {
+                if let Some(addr) = self.cluster.resolve_node(&node_a) {
+                    self.inner.unlink_remote(&addr, a, b);
+                }
+            }
+            (Some(node_a), Some(node_b)) => {
+                if let Some(addr) = self.cluster.resolve_node(&node_a) {
+                    self.inner.unlink_remote(&addr, a, b);
+                }
+                if let Some(addr) = self.cluster.resolve_node(&node_b) {
+                    self.inner.unlink_remote(&addr, a, b);
+                }
+            }
+        }
         Ok(())
     }
 
-    fn watch(&self, pid: u64, strategy: &str) -> PyResult<()> {
+    /// Supervise `pid` under `strategy`, restarting it with the same pid
+    /// (no factory). When `node` names a registered peer, `pid` is treated
+    /// as living there (see `spawn_on_node`) for the purposes of future
+    /// `is_alive`/`link` calls; restarting with no factory isn't
+    /// meaningful across a node boundary, so `node` only affects bookkeeping
+    /// here — use `supervise_with_factory` to actually restart remotely.
+    /// `max_restarts`/`within_secs` cap how many times `pid` may restart
+    /// within a sliding window before the supervisor gives up on it and
+    /// escalates to its own supervisor instead of looping forever;
+    /// `backoff_base_ms`/`backoff_cap_ms` control the exponential delay
+    /// applied between restarts (`base * 2^consecutive_failures`, capped).
+    /// All four default to `RestartLimits::default()` (unlimited, no
+    /// backoff) when omitted.
+    #[allow(clippy::too_many_arguments)]
+    fn watch(
+        &self,
+        pid: u64,
+        strategy: &str,
+        node: Option<String>,
+        max_restarts: Option<u32>,
+        within_secs: Option<u64>,
+        backoff_base_ms: Option<u64>,
+        backoff_cap_ms: Option<u64>,
+    ) -> PyResult<()> {
         use crate::supervisor::ChildSpec;
         use crate::supervisor::RestartStrategy;
         use std::sync::Arc;
@@ -672,28 +1429,49 @@ impl PyRuntime {
         let strat = match strategy.to_lowercase().as_str() {
             "restartone" | "restart_one" | "one" => RestartStrategy::RestartOne,
             "restartall" | "restart_all" | "all" => RestartStrategy::RestartAll,
+            "restforone" | "rest_for_one" => RestartStrategy::RestForOne,
             _ => return Err(pyo3::exceptions::PyValueError::new_err("invalid strategy")),
         };
 
+        if let Some(node_id) = node {
+            self.cluster.set_home(pid, node_id);
+        }
+
         let spec = ChildSpec {
             factory: Arc::new(move || Ok(pid)),
             strategy: strat,
+            limits: build_restart_limits(max_restarts, within_secs, backoff_base_ms, backoff_cap_ms),
         };
         self.inner.supervisor().add_child(pid, spec);
         Ok(())
     }
 
+    /// Attach a Python factory to a supervised child. When `node` names a
+    /// registered peer, the child is restarted *on that same node* when it
+    /// dies instead of locally, mirroring the spawn-a-closure-on-another-
+    /// process model: the factory still runs in this process (to decide
+    /// what to (re)spawn), but the resulting actor is recorded as living
+    /// on `node` for `is_alive`/`link` forwarding. `max_restarts`/
+    /// `within_secs`/`backoff_base_ms`/`backoff_cap_ms` behave as in
+    /// `watch`.
+    #[allow(clippy::too_many_arguments)]
     fn supervise_with_factory(
         &self,
         pid: u64,
         py_factory: PyObject,
         strategy: &str,
+        node: Option<String>,
+        max_restarts: Option<u32>,
+        within_secs: Option<u64>,
+        backoff_base_ms: Option<u64>,
+        backoff_cap_ms: Option<u64>,
     ) -> PyResult<()> {
         use std::sync::Arc;
 
         let strat = match strategy.to_lowercase().as_str() {
             "restartone" | "restart_one" | "one" => crate::supervisor::RestartStrategy::RestartOne,
             "restartall" | "restart_all" | "all" => crate::supervisor::RestartStrategy::RestartAll,
+            "restforone" | "rest_for_one" => crate::supervisor::RestartStrategy::RestForOne,
             _ => return Err(pyo3::exceptions::PyValueError::new_err("invalid strategy")),
         };
 
@@ -704,6 +1482,10 @@ impl PyRuntime {
             Ok::<u64, pyo3::PyErr>(pid)
         })?;
 
+        if let Some(node_id) = &node {
+            self.cluster.set_home(pid, node_id.clone());
+        }
+
         let factory_py = py_factory.clone();
         let factory_closure: Arc<dyn Fn() -> Result<crate::pid::Pid, String> + Send + Sync> =
         Arc::new(move || {
@@ -722,17 +1504,25 @@ impl PyRuntime {
             })
         });
 
-        self.inner.supervise(pid, factory_closure, strat);
+        let limits = build_restart_limits(max_restarts, within_secs, backoff_base_ms, backoff_cap_ms);
+        match node.as_deref().and_then(|n| self.cluster.resolve_node(n)) {
+            Some(addr) => self
+                .inner
+                .supervise_on_node(&addr, pid, factory_closure, strat, limits),
+            None => self.inner.supervise(pid, factory_closure, strat, limits),
+        }
         Ok(())
     }
 
-    /// Attach a Python factory to a path-scoped supervisor.
+    /// Attach a Python factory to a path-scoped supervisor. `node` behaves
+    /// as in `supervise_with_factory`.
     fn path_supervise_with_factory(
         &self,
         path: String,
         pid: u64,
         py_factory: PyObject,
         strategy: &str,
+        node: Option<String>,
     ) -> PyResult<()> {
         use std::sync::Arc;
 
@@ -743,9 +1533,14 @@ impl PyRuntime {
             "restartall" | "restart_all" | "all" => {
                 crate::supervisor::RestartStrategy::RestartAll
             }
+            "restforone" | "rest_for_one" => crate::supervisor::RestartStrategy::RestForOne,
             _ => return Err(pyo3::exceptions::PyValueError::new_err("invalid strategy")),
         };
 
+        if let Some(node_id) = &node {
+            self.cluster.set_home(pid, node_id.clone());
+        }
+
         // Validate we can call the factory once to obtain an initial pid
         let _initial_pid = Python::with_gil(|py| {
             let obj = py_factory.as_ref(py);
@@ -772,8 +1567,48 @@ impl PyRuntime {
             })
         });
 
-        self.inner
-        .path_supervise_with_factory(&path, pid, factory_closure, strat);
+        match node.as_deref().and_then(|n| self.cluster.resolve_node(n)) {
+            Some(addr) => self
+                .inner
+                .path_supervise_on_node(&addr, &path, pid, factory_closure, strat),
+            None => self
+                .inner
+                .path_supervise_with_factory(&path, pid, factory_closure, strat),
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_restart_limits_defaults_unset_fields() {
+        let defaults = crate::supervisor::RestartLimits::default();
+        let limits = build_restart_limits(Some(5), None, None, None);
+        assert_eq!(limits.max_restarts, 5);
+        assert_eq!(limits.within_secs, defaults.within_secs);
+        assert_eq!(limits.backoff_base_ms, defaults.backoff_base_ms);
+        assert_eq!(limits.backoff_cap_ms, defaults.backoff_cap_ms);
+    }
+
+    #[test]
+    fn build_restart_limits_all_set_overrides_every_default() {
+        let limits = build_restart_limits(Some(3), Some(60), Some(100), Some(5000));
+        assert_eq!(limits.max_restarts, 3);
+        assert_eq!(limits.within_secs, 60);
+        assert_eq!(limits.backoff_base_ms, 100);
+        assert_eq!(limits.backoff_cap_ms, 5000);
+    }
+
+    #[test]
+    fn build_restart_limits_all_unset_matches_defaults() {
+        let defaults = crate::supervisor::RestartLimits::default();
+        let limits = build_restart_limits(None, None, None, None);
+        assert_eq!(limits.max_restarts, defaults.max_restarts);
+        assert_eq!(limits.within_secs, defaults.within_secs);
+        assert_eq!(limits.backoff_base_ms, defaults.backoff_base_ms);
+        assert_eq!(limits.backoff_cap_ms, defaults.backoff_cap_ms);
+    }
+}
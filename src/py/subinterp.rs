@@ -0,0 +1,385 @@
+// src/py/subinterp.rs
+//! Opt-in per-worker sub-interpreter pool for true CPU parallelism across
+//! Python actors.
+//!
+//! `GilPool` (see `pool.rs`) runs every worker's callback under the single
+//! process-wide GIL via `Python::with_gil`, so N workers buy concurrency
+//! (overlapping I/O, releasing the GIL during blocking calls) but never
+//! parallelism for pure-Python CPU work. This module instead gives each
+//! worker thread its own CPython sub-interpreter with `PyInterpreterConfig
+//! { gil = PyInterpreterConfig_OWN_GIL, .. }` (CPython 3.12+), so N workers
+//! can run N Python bytecode loops truly concurrently.
+//!
+//! # Why this can't reuse `GilPool`'s `Arc<RwLock<PyObject>>` behavior
+//!
+//! A `PyObject` is a pointer into one interpreter's heap; sharing it with a
+//! second sub-interpreter is undefined behavior (CPython does not support
+//! passing live objects across interpreter boundaries; only fully
+//! independent interpreters with their own copy of every object are safe).
+//! So instead of a shared `behavior`, every worker is handed a
+//! [`BehaviorFactory`] and constructs its own independent copy of the
+//! callable inside its own sub-interpreter. `HotSwap` re-runs the (new)
+//! factory in every worker rather than installing one shared object.
+//!
+//! # Why this can't reuse pyo3's `Python<'py>` / `Python::with_gil`
+//!
+//! pyo3's GIL tracking is built on `PyGILState_Ensure`/`PyGILState_Release`,
+//! which CPython's own docs say is undefined once more than one interpreter
+//! holds its own GIL: `PyGILState_*` assumes a single global interpreter and
+//! a single GIL, and `PyGILState_Check` in particular is documented as
+//! unreliable across sub-interpreters with `OWN_GIL`. Using pyo3's
+//! `Python::with_gil` from one of these worker threads would silently
+//! corrupt that bookkeeping. So this module never constructs a pyo3
+//! `Python<'py>` token at all: every Python object touched inside a worker
+//! goes through raw `pyo3::ffi` calls, and the worker keeps the
+//! `PyThreadState` that `Py_NewInterpreterFromConfig` hands it current for
+//! its whole lifetime (swapping it back in with `PyThreadState_Swap` before
+//! `Py_EndInterpreter`) instead of relying on `PyGILState_*`.
+//!
+//! # Wire contract
+//!
+//! Because the codec in `codec.rs` (`py_to_msgpack`/`msgpack_to_py`) is
+//! built on pyo3's `Python<'py>`/`PyAny`, it can't safely run here either.
+//! Sub-interpreter workers therefore only support the raw `bytes -> bytes`
+//! contract that `Message::User` already uses for plain (non-`call`,
+//! non-`send_obj`) actors: the behavior callable receives a Python `bytes`
+//! object and (for `call`-style invocations) must return one, converted via
+//! raw `PyBytes_FromStringAndSize`/`PyBytes_AsStringAndSize` rather than the
+//! msgpack codec. `send_obj`/`recv_obj`/arbitrary-value `call` results are
+//! not supported in this mode.
+#![cfg(feature = "sub_interpreters")]
+#![allow(non_local_definitions)]
+
+use crossbeam_channel as cb_channel;
+use std::ffi::CString;
+use std::os::raw::c_int;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+use pyo3::ffi;
+
+use super::pool::CallResult;
+
+/// How a worker constructs its own copy of the actor's behavior. `PyObject`
+/// can't cross interpreter boundaries (see module docs), so every worker
+/// re-runs this rather than sharing one parsed object.
+#[derive(Clone)]
+pub(crate) enum BehaviorFactory {
+    /// Python source defining a module-level `behavior(msg: bytes) -> bytes`
+    /// function, `exec`'d fresh in each sub-interpreter.
+    Source(String),
+    /// `(module, attr)` imported fresh in each sub-interpreter, e.g.
+    /// `("myactor", "handle")` for `myactor.handle`.
+    Import(String, String),
+}
+
+/// Task variants accepted by a sub-interpreter worker. Mirrors
+/// `pool::PoolTask`, minus the shared `Arc<RwLock<PyObject>>` a sub-interpreter
+/// can't touch.
+pub(crate) enum SubTask {
+    Execute {
+        bytes: bytes::Bytes,
+        reply: Option<oneshot::Sender<CallResult>>,
+    },
+    HotSwap(BehaviorFactory),
+    Shutdown,
+}
+
+/// Minimum CPython version (3.12, `0x030C0000`) exposing
+/// `Py_NewInterpreterFromConfig`/`PyInterpreterConfig_OWN_GIL`. Checked at
+/// pool-creation time rather than left to fail confusingly deep inside a
+/// worker thread.
+const MIN_HEXVERSION: std::os::raw::c_long = 0x030C_0000;
+
+/// `true` if the running interpreter is new enough to support per-worker
+/// sub-interpreters with their own GIL.
+pub(crate) fn supported() -> bool {
+    version_supports_own_gil()
+}
+
+/// Pool of worker threads, each running its own CPython sub-interpreter.
+/// Unlike `GilPool`, there is no shared `PoolSender` queue policy here yet
+/// (bounded-queue backpressure for this mode is left for a follow-up); the
+/// queue is always unbounded.
+pub(crate) struct SubInterpreterPool {
+    sender: cb_channel::Sender<SubTask>,
+    threads: std::sync::Mutex<Vec<std::thread::JoinHandle<()>>>,
+}
+
+impl SubInterpreterPool {
+    /// Spawn `size` worker threads, each building its own sub-interpreter
+    /// and its own copy of `factory`'s behavior. Returns an error (instead
+    /// of panicking deep in a worker) if the running CPython is older than
+    /// 3.12.
+    pub(crate) fn new(size: usize, factory: BehaviorFactory) -> Result<Self, String> {
+        if !version_supports_own_gil() {
+            return Err(
+                "sub_interpreters requires CPython 3.12+ (Py_NewInterpreterFromConfig with \
+                 PyInterpreterConfig_OWN_GIL)"
+                    .to_string(),
+            );
+        }
+
+        let (tx, rx) = cb_channel::unbounded::<SubTask>();
+        let mut threads = Vec::with_capacity(size);
+        for _ in 0..size {
+            let rx = rx.clone();
+            let factory = factory.clone();
+            threads.push(std::thread::spawn(move || worker_loop(factory, rx)));
+        }
+        Ok(SubInterpreterPool {
+            sender: tx,
+            threads: std::sync::Mutex::new(threads),
+        })
+    }
+
+    pub(crate) fn submit(&self, task: SubTask) {
+        let _ = self.sender.send(task);
+    }
+
+    /// Re-run `factory` in every worker, replacing each worker's cached
+    /// behavior. Unlike `submit`, which hands a single task to whichever
+    /// worker happens to dequeue it first, a hot-swap must land on every
+    /// worker exactly once, so (like `shutdown`) this queues one `HotSwap`
+    /// per worker thread rather than one task total.
+    pub(crate) fn broadcast_hot_swap(&self, factory: BehaviorFactory) {
+        let count = self.threads.lock().unwrap().len();
+        for _ in 0..count {
+            let _ = self.sender.send(SubTask::HotSwap(factory.clone()));
+        }
+    }
+
+    /// Same draining contract as `GilPool::shutdown`: one `Shutdown` per
+    /// worker, queued behind whatever's already pending.
+    pub(crate) fn shutdown(&self, wait: bool) {
+        let threads = std::mem::take(&mut *self.threads.lock().unwrap());
+        for _ in 0..threads.len() {
+            let _ = self.sender.send(SubTask::Shutdown);
+        }
+        if wait {
+            for handle in threads {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+/// `sys.hexversion >= 0x030C0000` without needing a `Python<'py>` token:
+/// `ffi::PY_VERSION_HEX` is the version of the CPython headers this crate
+/// was built against.
+fn version_supports_own_gil() -> bool {
+    unsafe { ffi::PY_VERSION_HEX as std::os::raw::c_long >= MIN_HEXVERSION }
+}
+
+/// Body of a single sub-interpreter worker thread. Owns its `PyThreadState`
+/// for the thread's entire lifetime instead of acquiring/releasing it
+/// per-task the way `PyGILState_Ensure`/`Release` would (see module docs for
+/// why that API is unsafe to use here).
+fn worker_loop(factory: BehaviorFactory, rx: cb_channel::Receiver<SubTask>) {
+    let mut config: ffi::PyInterpreterConfig = unsafe { std::mem::zeroed() };
+    config.gil = ffi::PyInterpreterConfig_OWN_GIL;
+    config.use_main_obmalloc = 0;
+    config.check_multi_interp_extensions = 1;
+
+    let mut tstate: *mut ffi::PyThreadState = std::ptr::null_mut();
+    let status = unsafe { ffi::Py_NewInterpreterFromConfig(&mut tstate, &config) };
+    if unsafe { ffi::PyStatus_Exception(status) } != 0 || tstate.is_null() {
+        eprintln!("[Iris] sub_interpreters: failed to create sub-interpreter; worker exiting");
+        return;
+    }
+    // `Py_NewInterpreterFromConfig` returns with the new interpreter's GIL
+    // held and `tstate` current; this thread keeps it held for its entire
+    // lifetime rather than swapping it in/out per task, since there is no
+    // other interpreter sharing this OS thread.
+    let mut behavior = match load_behavior(&factory) {
+        Ok(obj) => obj,
+        Err(msg) => {
+            eprintln!("[Iris] sub_interpreters: failed to load behavior: {}", msg);
+            unsafe { end_interpreter(tstate) };
+            return;
+        }
+    };
+
+    loop {
+        match rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(SubTask::Execute { bytes, reply }) => {
+                let result = unsafe { call_behavior(behavior, &bytes) };
+                if let Some(reply) = reply {
+                    let _ = reply.send(result);
+                }
+            }
+            Ok(SubTask::HotSwap(new_factory)) => match load_behavior(&new_factory) {
+                Ok(new_behavior) => {
+                    unsafe { ffi::Py_DECREF(behavior) };
+                    behavior = new_behavior;
+                }
+                Err(msg) => {
+                    eprintln!(
+                        "[Iris] sub_interpreters: HotSwap factory failed, keeping old behavior: {}",
+                        msg
+                    );
+                }
+            },
+            Ok(SubTask::Shutdown) => break,
+            Err(cb_channel::RecvTimeoutError::Timeout) => continue,
+            Err(cb_channel::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    unsafe {
+        ffi::Py_DECREF(behavior);
+        end_interpreter(tstate);
+    }
+}
+
+/// Tear down this worker's sub-interpreter. Must run on the thread that
+/// created it, with its `PyThreadState` current (true here: this thread
+/// never swaps it out).
+unsafe fn end_interpreter(tstate: *mut ffi::PyThreadState) {
+    ffi::PyThreadState_Swap(tstate);
+    ffi::Py_EndInterpreter(tstate);
+}
+
+/// Build this worker's private copy of the behavior callable, entirely via
+/// raw `ffi` calls (no pyo3 `Python<'py>` token — see module docs).
+fn load_behavior(factory: &BehaviorFactory) -> Result<*mut ffi::PyObject, String> {
+    unsafe {
+        let obj = match factory {
+            BehaviorFactory::Source(src) => {
+                let code = CString::new(src.as_str())
+                    .map_err(|_| "behavior source contains a NUL byte".to_string())?;
+                let filename = CString::new("<sub_interpreter behavior>").unwrap();
+                let main_module = ffi::PyImport_AddModule(c"__main__".as_ptr() as *const _);
+                if main_module.is_null() {
+                    return Err(take_py_error("failed to access __main__"));
+                }
+                let globals = ffi::PyModule_GetDict(main_module);
+                let result = ffi::PyRun_String(
+                    code.as_ptr(),
+                    ffi::Py_file_input,
+                    globals,
+                    globals,
+                );
+                if result.is_null() {
+                    return Err(take_py_error("behavior source raised while executing"));
+                }
+                ffi::Py_DECREF(result);
+                let _ = filename;
+                let func = ffi::PyDict_GetItemString(globals, c"behavior".as_ptr() as *const _);
+                if func.is_null() {
+                    return Err(
+                        "behavior source did not define a top-level `behavior` function"
+                            .to_string(),
+                    );
+                }
+                ffi::Py_INCREF(func);
+                func
+            }
+            BehaviorFactory::Import(module, attr) => {
+                let module_cstr = CString::new(module.as_str())
+                    .map_err(|_| "module name contains a NUL byte".to_string())?;
+                let module_obj = ffi::PyImport_ImportModule(module_cstr.as_ptr());
+                if module_obj.is_null() {
+                    return Err(take_py_error(&format!("failed to import {}", module)));
+                }
+                let attr_cstr = CString::new(attr.as_str())
+                    .map_err(|_| "attribute name contains a NUL byte".to_string())?;
+                let func = ffi::PyObject_GetAttrString(module_obj, attr_cstr.as_ptr());
+                ffi::Py_DECREF(module_obj);
+                if func.is_null() {
+                    return Err(take_py_error(&format!("{} has no attribute {}", module, attr)));
+                }
+                func
+            }
+        };
+        if ffi::PyCallable_Check(obj) == 0 {
+            ffi::Py_DECREF(obj);
+            return Err("behavior factory did not produce a callable".to_string());
+        }
+        Ok(obj)
+    }
+}
+
+/// Call `behavior(bytes)`, converting to/from Python `bytes` with raw
+/// `PyBytes_*` calls rather than the `Python<'py>`-based msgpack codec (see
+/// module docs on the wire-contract limitation).
+unsafe fn call_behavior(behavior: *mut ffi::PyObject, payload: &[u8]) -> CallResult {
+    let arg = ffi::PyBytes_FromStringAndSize(payload.as_ptr() as *const _, payload.len() as isize);
+    if arg.is_null() {
+        return Err(take_py_error("failed to build argument bytes"));
+    }
+    let args = ffi::PyTuple_New(1);
+    if args.is_null() {
+        ffi::Py_DECREF(arg);
+        return Err(take_py_error("failed to build argument tuple"));
+    }
+    ffi::PyTuple_SetItem(args, 0, arg); // steals `arg`
+    let ret = ffi::PyObject_CallObject(behavior, args);
+    ffi::Py_DECREF(args);
+    if ret.is_null() {
+        return Err(take_py_error("Python actor exception"));
+    }
+    let out = if ffi::PyBytes_Check(ret) != 0 {
+        let mut buf: *mut std::os::raw::c_char = std::ptr::null_mut();
+        let mut len: ffi::Py_ssize_t = 0;
+        let rc = ffi::PyBytes_AsStringAndSize(ret, &mut buf, &mut len);
+        if rc != 0 {
+            ffi::Py_DECREF(ret);
+            return Err(take_py_error("failed to read return value"));
+        }
+        let slice = std::slice::from_raw_parts(buf as *const u8, len as usize);
+        Ok(slice.to_vec())
+    } else {
+        Err("sub_interpreters behavior must return bytes (send_obj-style return values \
+             are not supported in this mode)"
+            .to_string())
+    };
+    ffi::Py_DECREF(ret);
+    out
+}
+
+/// Format the current Python exception (if any) as a plain string and clear
+/// it, the raw-`ffi` equivalent of `PyErr::fetch`/`e.to_string()`.
+unsafe fn take_py_error(context: &str) -> String {
+    if ffi::PyErr_Occurred().is_null() {
+        return context.to_string();
+    }
+    let mut ptype = std::ptr::null_mut();
+    let mut pvalue = std::ptr::null_mut();
+    let mut ptraceback = std::ptr::null_mut();
+    ffi::PyErr_Fetch(&mut ptype, &mut pvalue, &mut ptraceback);
+    ffi::PyErr_NormalizeException(&mut ptype, &mut pvalue, &mut ptraceback);
+    let msg = if !pvalue.is_null() {
+        let str_obj = ffi::PyObject_Str(pvalue);
+        if str_obj.is_null() {
+            format!("{}: <unprintable exception>", context)
+        } else {
+            let utf8 = ffi::PyUnicode_AsUTF8(str_obj);
+            let text = if utf8.is_null() {
+                "<non-UTF8 exception message>".to_string()
+            } else {
+                std::ffi::CStr::from_ptr(utf8).to_string_lossy().into_owned()
+            };
+            ffi::Py_DECREF(str_obj);
+            format!("{}: {}", context, text)
+        }
+    } else {
+        context.to_string()
+    };
+    if !ptype.is_null() {
+        ffi::Py_DECREF(ptype);
+    }
+    if !pvalue.is_null() {
+        ffi::Py_DECREF(pvalue);
+    }
+    if !ptraceback.is_null() {
+        ffi::Py_DECREF(ptraceback);
+    }
+    msg
+}
+
+/// Process-wide handle so `PyRuntime` can lazily create (and later shut
+/// down) one sub-interpreter pool, mirroring `pool::GIL_WORKER_POOL`.
+pub(crate) static SUB_INTERPRETER_POOL: std::sync::OnceLock<Arc<SubInterpreterPool>> =
+    std::sync::OnceLock::new();
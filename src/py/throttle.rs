@@ -0,0 +1,126 @@
+// src/py/throttle.rs
+//! Throttled batch scheduling for `spawn_py_handler_throttled`, following
+//! the threadshare throttling-executor idea: group wakeups and service
+//! them on a fixed interval instead of immediately.
+//!
+//! `spawn_py_handler` pays a `Python::with_gil` + `call1` on every single
+//! message, which dominates cost for workloads with many low-traffic
+//! actors. Here, a single shared ticker (one `tokio::time::sleep` loop,
+//! fanned out over a `watch` channel) fires every `set_throttle`-configured
+//! interval; each throttled actor buffers incoming user messages as they
+//! arrive and only acquires the GIL when the ticker fires, delivering
+//! everything buffered since the last tick to the Python callback in one
+//! batch (a `PyList` of `bytes`). A `HotSwap` first flushes whatever is
+//! already buffered, so it stays ordered relative to the user messages
+//! around it, and the actor does one last flush after its mailbox closes
+//! so nothing buffered is lost on stop/exit.
+#![allow(non_local_definitions)]
+
+use bytes::Bytes;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyList};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use crate::mailbox::{Message, MailboxReceiver, SystemMessage};
+
+/// Default batching interval (microseconds) until `set_throttle` is called.
+const DEFAULT_THROTTLE_INTERVAL_US: u64 = 1000;
+
+static THROTTLE_INTERVAL_US: AtomicU64 = AtomicU64::new(DEFAULT_THROTTLE_INTERVAL_US);
+
+/// Set the batching interval (microseconds) shared by every throttled
+/// actor. Picked up by the ticker on its next sleep, so already-running
+/// actors adjust without needing to be respawned.
+pub(crate) fn set_throttle(interval_us: u64) {
+    THROTTLE_INTERVAL_US.store(interval_us.max(1), Ordering::Relaxed);
+}
+
+fn throttle_interval() -> Duration {
+    Duration::from_micros(THROTTLE_INTERVAL_US.load(Ordering::Relaxed))
+}
+
+static THROTTLE_TICK: OnceLock<watch::Sender<u64>> = OnceLock::new();
+
+/// Subscribe to the shared ticker, starting its background task on first
+/// use. Every throttled actor holds its own `watch::Receiver` clone, so
+/// each independently knows whether it has caught up to the latest tick.
+fn subscribe() -> watch::Receiver<u64> {
+    let tx = THROTTLE_TICK.get_or_init(|| {
+        let (tx, _rx) = watch::channel(0u64);
+        let ticker_tx = tx.clone();
+        crate::RUNTIME.spawn(async move {
+            let mut n = 0u64;
+            loop {
+                tokio::time::sleep(throttle_interval()).await;
+                n += 1;
+                if ticker_tx.send(n).is_err() {
+                    break;
+                }
+            }
+        });
+        tx
+    });
+    tx.subscribe()
+}
+
+/// Deliver whatever's buffered to `behavior` as one `PyList` of `bytes`
+/// under a single GIL acquisition; a no-op if nothing has been buffered.
+fn flush(behavior: &parking_lot::RwLock<PyObject>, batch: &mut Vec<Bytes>) {
+    if batch.is_empty() {
+        return;
+    }
+    Python::with_gil(|py| {
+        let list = PyList::new(py, batch.drain(..).map(|b| PyBytes::new(py, &b)));
+        let guard = behavior.read();
+        let cb = guard.as_ref(py);
+        if let Err(e) = cb.call1((list,)) {
+            eprintln!("[Iris] Python throttled actor exception: {}", e);
+            e.print(py);
+        }
+    });
+}
+
+/// Body of one throttled actor. Buffers `User` messages as they arrive and
+/// only acquires the GIL (via `flush`) when the shared ticker fires or the
+/// mailbox closes, so many near-idle actors can share one wakeup/GIL cost
+/// instead of paying it per message.
+pub(crate) async fn run_throttled_actor(
+    behavior: std::sync::Arc<parking_lot::RwLock<PyObject>>,
+    mut rx: MailboxReceiver,
+) {
+    let mut tick = subscribe();
+    let mut batch: Vec<Bytes> = Vec::new();
+
+    loop {
+        tokio::select! {
+            changed = tick.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                flush(&behavior, &mut batch);
+            }
+            msg = rx.recv() => {
+                match msg {
+                    None => break,
+                    Some(Message::User(bytes)) => batch.push(bytes),
+                    Some(Message::System(SystemMessage::HotSwap(ptr))) => {
+                        // Flush first so the swap lands strictly after every
+                        // message buffered ahead of it.
+                        flush(&behavior, &mut batch);
+                        Python::with_gil(|py| unsafe {
+                            *behavior.write() =
+                                PyObject::from_owned_ptr(py, ptr as *mut pyo3::ffi::PyObject);
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Mailbox closed; deliver anything still buffered before the actor stops.
+    flush(&behavior, &mut batch);
+}
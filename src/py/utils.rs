@@ -6,6 +6,21 @@ use crate::mailbox;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 
+/// Raised by a non-trapping mailbox when it receives a non-`Normal` EXIT
+/// from a linked actor instead of surfacing it as an ordinary message.
+pyo3::create_exception!(iris, ActorExit, pyo3::exceptions::PyException);
+
+fn reason_str(reason: &mailbox::ExitReason) -> String {
+    match reason {
+        mailbox::ExitReason::Normal => "normal".to_string(),
+        mailbox::ExitReason::Panic => "panic".to_string(),
+        mailbox::ExitReason::Timeout => "timeout".to_string(),
+        mailbox::ExitReason::Killed => "killed".to_string(),
+        mailbox::ExitReason::Oom => "oom".to_string(),
+        mailbox::ExitReason::Other(s) => s.clone(),
+    }
+}
+
 /// Python-friendly structured system message used during conversions.
 #[pyclass]
 #[derive(Clone)]
@@ -18,6 +33,13 @@ pub struct PySystemMessage {
     pub reason: String,
     #[pyo3(get)]
     pub metadata: Option<String>,
+    /// `ASSERT`/`RETRACT`'s dataspace subscription handle, or `DOWN`'s
+    /// monitor handle. `None` for every other `type_name`.
+    #[pyo3(get)]
+    pub handle: Option<u64>,
+    /// `ASSERT`'s fact payload. `None` for every other `type_name`.
+    #[pyo3(get)]
+    pub value: Option<Py<PyBytes>>,
 }
 
 /// Convert a Rust `Message` into a Python object suitable
@@ -26,20 +48,15 @@ pub(crate) fn message_to_py(py: Python, msg: mailbox::Message) -> PyObject {
     match msg {
         mailbox::Message::User(b) => PyBytes::new(py, &b).into_py(py),
         mailbox::Message::System(mailbox::SystemMessage::Exit(info)) => {
-            let reason = match info.reason {
-                mailbox::ExitReason::Normal => "normal".to_string(),
-                mailbox::ExitReason::Panic => "panic".to_string(),
-                mailbox::ExitReason::Timeout => "timeout".to_string(),
-                mailbox::ExitReason::Killed => "killed".to_string(),
-                mailbox::ExitReason::Oom => "oom".to_string(),
-                mailbox::ExitReason::Other(ref s) => s.clone(),
-            };
+            let reason = reason_str(&info.reason);
 
             PySystemMessage {
                 type_name: "EXIT".to_string(),
                 target_pid: Some(info.from),
                 reason,
                 metadata: info.metadata.clone(),
+                handle: None,
+                value: None,
             }
             .into_py(py)
         }
@@ -49,6 +66,8 @@ pub(crate) fn message_to_py(py: Python, msg: mailbox::Message) -> PyObject {
                 target_pid: None,
                 reason: "".to_string(),
                 metadata: None,
+                handle: None,
+                value: None,
             }
             .into_py(py)
         }
@@ -57,6 +76,8 @@ pub(crate) fn message_to_py(py: Python, msg: mailbox::Message) -> PyObject {
             target_pid: None,
             reason: "".to_string(),
             metadata: None,
+            handle: None,
+            value: None,
         }
         .into_py(py),
         mailbox::Message::System(mailbox::SystemMessage::Pong) => PySystemMessage {
@@ -64,11 +85,105 @@ pub(crate) fn message_to_py(py: Python, msg: mailbox::Message) -> PyObject {
             target_pid: None,
             reason: "".to_string(),
             metadata: None,
+            handle: None,
+            value: None,
+        }
+        .into_py(py),
+        mailbox::Message::System(mailbox::SystemMessage::Call(..)) => {
+            // The pooled-actor `call` path consumes `Call` before it ever
+            // reaches a plain `recv`; this arm only keeps the match exhaustive.
+            PySystemMessage {
+                type_name: "CALL".to_string(),
+                target_pid: None,
+                reason: "".to_string(),
+                metadata: None,
+                handle: None,
+                value: None,
+            }
+            .into_py(py)
+        }
+        mailbox::Message::System(mailbox::SystemMessage::Assert { handle, value }) => {
+            PySystemMessage {
+                type_name: "ASSERT".to_string(),
+                target_pid: None,
+                reason: "".to_string(),
+                metadata: None,
+                handle: Some(handle),
+                value: Some(PyBytes::new(py, &value).into()),
+            }
+            .into_py(py)
+        }
+        mailbox::Message::System(mailbox::SystemMessage::Retract { handle }) => {
+            PySystemMessage {
+                type_name: "RETRACT".to_string(),
+                target_pid: None,
+                reason: "".to_string(),
+                metadata: None,
+                handle: Some(handle),
+                value: None,
+            }
+            .into_py(py)
+        }
+        mailbox::Message::System(mailbox::SystemMessage::Synced) => PySystemMessage {
+            type_name: "SYNCED".to_string(),
+            target_pid: None,
+            reason: "".to_string(),
+            metadata: None,
+            handle: None,
+            value: None,
         }
         .into_py(py),
+        mailbox::Message::System(mailbox::SystemMessage::Down { handle, pid, reason }) => {
+            PySystemMessage {
+                type_name: "DOWN".to_string(),
+                target_pid: Some(pid),
+                reason: reason_str(&reason.reason),
+                metadata: reason.metadata.clone(),
+                handle: Some(handle),
+                value: None,
+            }
+            .into_py(py)
+        }
     }
 }
 
+/// Convert a message the way `message_to_py` does, except that when `msg` is
+/// a non-`Normal` EXIT and `trap_exit` is `false`, it is treated as fatal:
+/// instead of handing back a `PySystemMessage`, this raises `ActorExit` so
+/// the caller's `recv` propagates the linked failure as an exception.
+pub(crate) fn message_to_py_checked(
+    py: Python,
+    msg: mailbox::Message,
+    trap_exit: bool,
+) -> PyResult<PyObject> {
+    if !trap_exit {
+        if let mailbox::Message::System(mailbox::SystemMessage::Exit(ref info)) = msg {
+            if info.reason != mailbox::ExitReason::Normal {
+                return Err(ActorExit::new_err(format!(
+                    "linked actor {} exited: {}",
+                    info.from,
+                    reason_str(&info.reason)
+                )));
+            }
+        }
+    }
+    Ok(message_to_py(py, msg))
+}
+
+/// Convert a message the way `message_to_py_checked` does, except a `User`
+/// payload is decoded as MessagePack instead of handed back as raw bytes.
+/// Pairs with `PyRuntime.send_obj` / `PyMailbox.recv_obj`.
+pub(crate) fn message_to_py_obj(
+    py: Python,
+    msg: mailbox::Message,
+    trap_exit: bool,
+) -> PyResult<PyObject> {
+    if let mailbox::Message::User(ref b) = msg {
+        return super::codec::msgpack_to_py(py, b);
+    }
+    message_to_py_checked(py, msg, trap_exit)
+}
+
 /// Run a Python matcher callback against a Rust message.
 pub(crate) fn run_python_matcher(
     py: Python,
@@ -95,6 +210,8 @@ pub(crate) fn run_python_matcher(
                     target_pid: Some(info.from),
                     reason,
                     metadata: info.metadata.clone(),
+                    handle: None,
+                    value: None,
                 };
                 match matcher.call1(py, (obj.into_py(py),)) {
                     Ok(val) => val.extract::<bool>(py).unwrap_or(false),
@@ -107,6 +224,8 @@ pub(crate) fn run_python_matcher(
                     target_pid: None,
                     reason: "".to_string(),
                     metadata: None,
+                    handle: None,
+                    value: None,
                 };
                 match matcher.call1(py, (obj.into_py(py),)) {
                     Ok(val) => val.extract::<bool>(py).unwrap_or(false),
@@ -119,6 +238,8 @@ pub(crate) fn run_python_matcher(
                     target_pid: None,
                     reason: "".to_string(),
                     metadata: None,
+                    handle: None,
+                    value: None,
                 };
                 match matcher.call1(py, (obj.into_py(py),)) {
                     Ok(val) => val.extract::<bool>(py).unwrap_or(false),
@@ -131,6 +252,69 @@ pub(crate) fn run_python_matcher(
                     target_pid: None,
                     reason: "".to_string(),
                     metadata: None,
+                    handle: None,
+                    value: None,
+                };
+                match matcher.call1(py, (obj.into_py(py),)) {
+                    Ok(val) => val.extract::<bool>(py).unwrap_or(false),
+                    Err(_) => false,
+                }
+            }
+            mailbox::SystemMessage::Call(..) => {
+                // Consumed by the pooled-actor call path before a selective
+                // receive's matcher would ever see it.
+                false
+            }
+            mailbox::SystemMessage::Assert { handle, value } => {
+                let obj = PySystemMessage {
+                    type_name: "ASSERT".to_string(),
+                    target_pid: None,
+                    reason: "".to_string(),
+                    metadata: None,
+                    handle: Some(*handle),
+                    value: Some(PyBytes::new(py, value).into()),
+                };
+                match matcher.call1(py, (obj.into_py(py),)) {
+                    Ok(val) => val.extract::<bool>(py).unwrap_or(false),
+                    Err(_) => false,
+                }
+            }
+            mailbox::SystemMessage::Retract { handle } => {
+                let obj = PySystemMessage {
+                    type_name: "RETRACT".to_string(),
+                    target_pid: None,
+                    reason: "".to_string(),
+                    metadata: None,
+                    handle: Some(*handle),
+                    value: None,
+                };
+                match matcher.call1(py, (obj.into_py(py),)) {
+                    Ok(val) => val.extract::<bool>(py).unwrap_or(false),
+                    Err(_) => false,
+                }
+            }
+            mailbox::SystemMessage::Synced => {
+                let obj = PySystemMessage {
+                    type_name: "SYNCED".to_string(),
+                    target_pid: None,
+                    reason: "".to_string(),
+                    metadata: None,
+                    handle: None,
+                    value: None,
+                };
+                match matcher.call1(py, (obj.into_py(py),)) {
+                    Ok(val) => val.extract::<bool>(py).unwrap_or(false),
+                    Err(_) => false,
+                }
+            }
+            mailbox::SystemMessage::Down { handle, pid, reason } => {
+                let obj = PySystemMessage {
+                    type_name: "DOWN".to_string(),
+                    target_pid: Some(*pid),
+                    reason: reason_str(&reason.reason),
+                    metadata: reason.metadata.clone(),
+                    handle: Some(*handle),
+                    value: None,
                 };
                 match matcher.call1(py, (obj.into_py(py),)) {
                     Ok(val) => val.extract::<bool>(py).unwrap_or(false),
@@ -140,3 +324,76 @@ pub(crate) fn run_python_matcher(
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    fn extract(py: Python, obj: PyObject) -> Py<PySystemMessage> {
+        obj.extract(py).expect("expected a PySystemMessage")
+    }
+
+    #[test]
+    fn assert_round_trips_handle_and_value() {
+        Python::with_gil(|py| {
+            let msg = mailbox::Message::System(mailbox::SystemMessage::Assert {
+                handle: 7,
+                value: Bytes::from_static(b"fact"),
+            });
+            let got = extract(py, message_to_py(py, msg));
+            let got = got.borrow(py);
+            assert_eq!(got.type_name, "ASSERT");
+            assert_eq!(got.handle, Some(7));
+            let value = got.value.as_ref().expect("value should be set");
+            assert_eq!(value.as_ref(py).as_bytes(), b"fact");
+        });
+    }
+
+    #[test]
+    fn retract_round_trips_handle_with_no_value() {
+        Python::with_gil(|py| {
+            let msg = mailbox::Message::System(mailbox::SystemMessage::Retract { handle: 7 });
+            let got = extract(py, message_to_py(py, msg));
+            let got = got.borrow(py);
+            assert_eq!(got.type_name, "RETRACT");
+            assert_eq!(got.handle, Some(7));
+            assert!(got.value.is_none());
+        });
+    }
+
+    #[test]
+    fn synced_carries_no_payload() {
+        Python::with_gil(|py| {
+            let msg = mailbox::Message::System(mailbox::SystemMessage::Synced);
+            let got = extract(py, message_to_py(py, msg));
+            let got = got.borrow(py);
+            assert_eq!(got.type_name, "SYNCED");
+            assert!(got.handle.is_none());
+            assert!(got.value.is_none());
+        });
+    }
+
+    #[test]
+    fn down_round_trips_handle_pid_and_reason() {
+        Python::with_gil(|py| {
+            let msg = mailbox::Message::System(mailbox::SystemMessage::Down {
+                handle: 3,
+                pid: 42,
+                reason: mailbox::ExitInfo {
+                    from: 42,
+                    reason: mailbox::ExitReason::Killed,
+                    metadata: Some("watchdog".to_string()),
+                },
+            });
+            let got = extract(py, message_to_py(py, msg));
+            let got = got.borrow(py);
+            assert_eq!(got.type_name, "DOWN");
+            assert_eq!(got.target_pid, Some(42));
+            assert_eq!(got.handle, Some(3));
+            assert_eq!(got.reason, "killed");
+            assert_eq!(got.metadata.as_deref(), Some("watchdog"));
+            assert!(got.value.is_none());
+        });
+    }
+}
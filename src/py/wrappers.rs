@@ -6,10 +6,239 @@ use crate::buffer::{global_registry, BufferId};
 use pyo3::prelude::*;
 use pyo3::types::PyTuple;
 use pyo3::wrap_pyfunction;
+use pyo3_asyncio::tokio::future_into_py;
 use std::os::raw::{c_char, c_void};
+use std::sync::Arc;
+use tokio::sync::Mutex as TokioMutex;
 
 use super::runtime::PyRuntime;
 
+/// Blocking-first completion handle for `PyRuntime.ask`, following the
+/// codemp bindings' "RustPromise with `pyawait()`" pattern: a synchronous
+/// Python caller gets a real signal for "has the handler replied yet?"
+/// instead of `tokio::time::sleep` + polling `get_messages`. Wraps the same
+/// `oneshot::Receiver<CallResult>` `call`/`PyJoinHandle` use, so `ask` is a
+/// drop-in synchronous sibling of `call` rather than a separate dispatch
+/// path. An asyncio caller can `await` it directly too — `result()`/
+/// `__await__` wrap the same receiver in `pyo3_asyncio::tokio::future_into_py`,
+/// the way `PyPromise` does, so a coroutine actor waiting on `ask()` never
+/// has to block a thread or fall back to polling `poll()`.
+#[pyclass]
+pub struct PyRustPromise {
+    rx: Arc<TokioMutex<Option<tokio::sync::oneshot::Receiver<super::pool::CallResult>>>>,
+}
+
+impl PyRustPromise {
+    pub(crate) fn new(rx: tokio::sync::oneshot::Receiver<super::pool::CallResult>) -> Self {
+        Self {
+            rx: Arc::new(TokioMutex::new(Some(rx))),
+        }
+    }
+}
+
+fn promise_already_consumed() -> PyErr {
+    pyo3::exceptions::PyRuntimeError::new_err(
+        "RustPromise already consumed (call pyawait()/poll()/await only once)",
+    )
+}
+
+fn promise_dropped() -> PyErr {
+    pyo3::exceptions::PyRuntimeError::new_err("pooled actor was dropped before replying")
+}
+
+#[pymethods]
+impl PyRustPromise {
+    /// Block the calling thread until the handler replies, decoding the
+    /// MessagePack result the same way `PyJoinHandle.result()` does. Raises
+    /// `RuntimeError` if the callback itself raised, if the actor was
+    /// dropped before replying, or if the promise was already consumed.
+    fn pyawait(&self, py: Python) -> PyResult<PyObject> {
+        let receiver = {
+            let mut guard = self.rx.try_lock().map_err(|_| promise_already_consumed())?;
+            guard.take().ok_or_else(promise_already_consumed)?
+        };
+        let result = py.allow_threads(|| {
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                tokio::task::block_in_place(|| handle.block_on(receiver))
+            } else {
+                crate::RUNTIME.block_on(receiver)
+            }
+        });
+        match result {
+            Ok(Ok(bytes)) => super::codec::msgpack_to_py(py, &bytes),
+            Ok(Err(msg)) => Err(pyo3::exceptions::PyRuntimeError::new_err(msg)),
+            Err(_) => Err(promise_dropped()),
+        }
+    }
+
+    /// Non-blocking check: `None` if the handler hasn't replied yet
+    /// (the promise stays usable — call `poll()`/`pyawait()` again later),
+    /// otherwise the same result `pyawait()` would return.
+    fn poll(&self, py: Python) -> PyResult<Option<PyObject>> {
+        let mut guard = self.rx.try_lock().map_err(|_| promise_already_consumed())?;
+        let receiver = guard.as_mut().ok_or_else(promise_already_consumed)?;
+        match receiver.try_recv() {
+            Ok(Ok(bytes)) => {
+                *guard = None;
+                Ok(Some(super::codec::msgpack_to_py(py, &bytes)?))
+            }
+            Ok(Err(msg)) => {
+                *guard = None;
+                Err(pyo3::exceptions::PyRuntimeError::new_err(msg))
+            }
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty) => Ok(None),
+            Err(tokio::sync::oneshot::error::TryRecvError::Closed) => {
+                *guard = None;
+                Err(promise_dropped())
+            }
+        }
+    }
+
+    /// Await this promise from an asyncio loop, cooperating with the
+    /// running event loop instead of blocking a thread: schedules the
+    /// continuation on the shared tokio runtime and resumes Python only
+    /// once the handler has replied.
+    fn result<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let rx = self.rx.clone();
+        future_into_py(py, async move {
+            let receiver = rx.lock().await.take().ok_or_else(promise_already_consumed)?;
+            match receiver.await.map_err(|_| promise_dropped())? {
+                Ok(bytes) => Python::with_gil(|py| super::codec::msgpack_to_py(py, &bytes)),
+                Err(msg) => Err(pyo3::exceptions::PyRuntimeError::new_err(msg)),
+            }
+        })
+    }
+
+    /// `__await__` support so `await rt.ask(...)` works the same way an
+    /// asyncio future does, by delegating to `result()`'s coroutine.
+    fn __await__<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        self.result(py)?.call_method0("__await__")
+    }
+}
+
+/// One `tracing` event, captured off the hot path and handed across the
+/// channel `PyLoggerLayer` feeds. Formatted eagerly in `on_event` so the
+/// draining task only needs the GIL, not a borrow into `tracing`'s own
+/// span/event data.
+struct LogEvent {
+    level: &'static str,
+    target: String,
+    message: String,
+    micros: u128,
+}
+
+/// `tracing::field::Visit` that pulls out just the conventional `message`
+/// field, the same one `tracing_subscriber`'s own `fmt` layer prints.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl tracing::field::Visit for MessageVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if field.name() == "message" {
+            self.0 = value.to_string();
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+/// `tracing_subscriber::Layer` installed by `init()`: formats each event
+/// (level, target, message, a microsecond timestamp) and pushes it onto an
+/// unbounded channel instead of calling back into Python directly, so a
+/// `tracing` call inside an actor never has to wait on the GIL the actor
+/// itself might already be holding. The channel's other end is drained by
+/// a task on the `Driver`'s own runtime.
+struct PyLoggerLayer {
+    tx: tokio::sync::mpsc::UnboundedSender<LogEvent>,
+}
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for PyLoggerLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let micros = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_micros())
+            .unwrap_or(0);
+        let _ = self.tx.send(LogEvent {
+            level: event.metadata().level().as_str(),
+            target: event.metadata().target().to_string(),
+            message: visitor.0,
+            micros,
+        });
+    }
+}
+
+/// Background machinery started by `init()`: owns the dedicated tokio
+/// runtime that drains `PyLoggerLayer`'s channel and calls `logger_cb` with
+/// each formatted event, re-acquiring the GIL only for that call so log
+/// volume can never block an actor's own execution. Mirrors the codemp
+/// bindings' "logging callback drives the runtime" + `Driver.stop()`
+/// design.
+#[pyclass]
+pub struct Driver {
+    runtime: std::sync::Mutex<Option<tokio::runtime::Runtime>>,
+}
+
+#[pymethods]
+impl Driver {
+    /// Shut down the background runtime, dropping the log-draining task
+    /// along with it. Idempotent: a second call is a no-op.
+    fn stop(&self) {
+        if let Some(rt) = self.runtime.lock().unwrap().take() {
+            rt.shutdown_background();
+        }
+    }
+}
+
+/// Install a `tracing-subscriber` layer that forwards every formatted log
+/// event to `logger_cb(level, target, message, micros)` and return a
+/// `Driver` owning the background tokio runtime that drains them. Only
+/// `INFO` and coarser events are forwarded unless `debug` is set, in which
+/// case `DEBUG`/`TRACE` are included too — giving Python embedders real
+/// lifecycle and crash visibility (e.g. an actor's `raise Exception(...)`)
+/// without having to attach a Rust-side subscriber of their own.
+///
+/// `tracing` only allows one global subscriber per process, so calling
+/// this twice raises `RuntimeError`.
+#[pyfunction(name = "init")]
+fn init_logging(logger_cb: PyObject, debug: bool) -> PyResult<Driver> {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<LogEvent>();
+    let filter = if debug {
+        tracing::level_filters::LevelFilter::TRACE
+    } else {
+        tracing::level_filters::LevelFilter::INFO
+    };
+    let subscriber = tracing_subscriber::registry().with(PyLoggerLayer { tx }.with_filter(filter));
+    tracing::subscriber::set_global_default(subscriber).map_err(|e| {
+        pyo3::exceptions::PyRuntimeError::new_err(format!("tracing subscriber already installed: {e}"))
+    })?;
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    runtime.spawn(async move {
+        while let Some(event) = rx.recv().await {
+            Python::with_gil(|py| {
+                let _ = logger_cb.call1(py, (event.level, event.target, event.message, event.micros as u64));
+            });
+        }
+    });
+
+    Ok(Driver {
+        runtime: std::sync::Mutex::new(Some(runtime)),
+    })
+}
+
 extern "C" fn capsule_destructor(capsule: *mut pyo3::ffi::PyObject) {
     if capsule.is_null() {
         return;
@@ -139,6 +368,21 @@ fn populate_module(m: &PyModule) -> PyResult<()> {
     m.add_class::<PyRuntime>()?;
     m.add_class::<super::utils::PySystemMessage>()?;
     m.add_class::<super::mailbox::PyMailbox>()?;
+    m.add_class::<super::pool::PyJoinHandle>()?;
+    m.add_class::<super::promise::PyPromise>()?;
+    m.add_class::<super::preserves::Embedded>()?;
+    m.add_class::<PyRustPromise>()?;
+    m.add_class::<Driver>()?;
+    m.add_function(wrap_pyfunction!(init_logging, m)?)?;
+    m.add("ActorExit", m.py().get_type::<super::utils::ActorExit>())?;
+    m.add(
+        "MsgpackDecodeError",
+        m.py().get_type::<super::codec::MsgpackDecodeError>(),
+    )?;
+    m.add(
+        "PreservesDecodeError",
+        m.py().get_type::<super::preserves::PreservesDecodeError>(),
+    )?;
     #[cfg(feature = "pyo3")]
     m.add_function(wrap_pyfunction!(allocate_buffer, m)?)?;
     // Path-based registry helpers (module-level convenience wrappers)
@@ -171,3 +415,103 @@ pub fn make_module(py: Python) -> PyResult<Py<PyModule>> {
 
 #[cfg(feature = "pyo3")]
 pub fn init() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_promise() -> (
+        tokio::sync::oneshot::Sender<super::super::pool::CallResult>,
+        PyRustPromise,
+    ) {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        (tx, PyRustPromise::new(rx))
+    }
+
+    #[test]
+    fn pyawait_decodes_the_reply() {
+        Python::with_gil(|py| {
+            let (tx, promise) = make_promise();
+            let value = py.eval("42", None, None).unwrap();
+            tx.send(Ok(super::super::codec::py_to_msgpack(py, value).unwrap()))
+                .unwrap();
+            let got: i64 = promise.pyawait(py).unwrap().extract(py).unwrap();
+            assert_eq!(got, 42);
+        });
+    }
+
+    #[test]
+    fn pyawait_twice_raises_already_consumed() {
+        Python::with_gil(|py| {
+            let (tx, promise) = make_promise();
+            let value = py.eval("None", None, None).unwrap();
+            tx.send(Ok(super::super::codec::py_to_msgpack(py, value).unwrap()))
+                .unwrap();
+            promise.pyawait(py).expect("first pyawait should succeed");
+            let err = promise
+                .pyawait(py)
+                .expect_err("second pyawait should find the promise already consumed");
+            assert!(err.to_string().contains("already consumed"));
+        });
+    }
+
+    #[test]
+    fn poll_is_none_until_the_reply_arrives_then_consumes() {
+        Python::with_gil(|py| {
+            let (tx, promise) = make_promise();
+            assert!(promise.poll(py).unwrap().is_none());
+
+            let value = py.eval("True", None, None).unwrap();
+            tx.send(Ok(super::super::codec::py_to_msgpack(py, value).unwrap()))
+                .unwrap();
+            let got: bool = promise
+                .poll(py)
+                .unwrap()
+                .expect("reply should be ready")
+                .extract(py)
+                .unwrap();
+            assert!(got);
+
+            let err = promise
+                .poll(py)
+                .expect_err("polling an already-consumed promise should error");
+            assert!(err.to_string().contains("already consumed"));
+        });
+    }
+
+    // Only one test installs a tracing subscriber: `tracing::subscriber::set_global_default`
+    // can only succeed once per process, so a second `init_logging` call in the same
+    // test binary would hit the "already installed" error path instead of exercising
+    // the round-trip this test is after.
+    #[test]
+    fn driver_round_trips_a_log_event_to_the_callback() {
+        Python::with_gil(|py| {
+            let module = PyModule::from_code(
+                py,
+                "calls = []\ndef cb(level, target, message, micros):\n    calls.append((level, target, message, micros))\n",
+                "wrappers_test_driver.py",
+                "wrappers_test_driver",
+            )
+            .unwrap();
+            let cb = module.getattr("cb").unwrap().to_object(py);
+
+            let driver = init_logging(cb, false).expect("init_logging should install the layer");
+            tracing::info!(target: "iris::py::wrappers::test", "hello from test");
+
+            let calls = module.getattr("calls").unwrap();
+            let deadline = std::time::Instant::now() + std::time::Duration::from_secs(1);
+            while calls.len().unwrap() == 0 && std::time::Instant::now() < deadline {
+                py.allow_threads(|| std::thread::sleep(std::time::Duration::from_millis(10)));
+            }
+
+            assert_eq!(calls.len().unwrap(), 1, "callback should have fired exactly once");
+            let (level, target, message, _micros): (String, String, String, u64) =
+                calls.get_item(0).unwrap().extract().unwrap();
+            assert_eq!(level, "INFO");
+            assert_eq!(target, "iris::py::wrappers::test");
+            assert_eq!(message, "hello from test");
+
+            driver.stop();
+        });
+    }
+}
@@ -4,24 +4,50 @@
 
 use crate::pid::Pid;
 use dashmap::DashMap;
+use futures::Stream;
+use tokio::sync::broadcast;
+
+/// Capacity of the broadcast buffer behind `subscribe`. Registry events are
+/// a best-effort notification layer, not a durable log: a subscriber that
+/// falls this far behind silently skips the buffered events rather than
+/// blocking `register`/`unregister`.
+const EVENT_BUFFER: usize = 256;
+
+/// A registry mutation, emitted to every live `subscribe` stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryEvent {
+    Registered { name: String, pid: Pid },
+    Replaced { name: String, old: Pid, new: Pid },
+    Unregistered { name: String, pid: Pid },
+}
 
 pub struct NameRegistry {
     /// Mapping of human-readable names to PIDs.
     names: DashMap<String, Pid>,
+    /// Fan-out of `RegistryEvent`s; kept even with zero subscribers; sends
+    /// are fire-and-forget since `broadcast::Sender::send` only errors when
+    /// there are no receivers at all.
+    events: broadcast::Sender<RegistryEvent>,
 }
 
 impl NameRegistry {
     /// Create a new, empty name registry.
     pub fn new() -> Self {
+        let (events, _) = broadcast::channel(EVENT_BUFFER);
         Self {
             names: DashMap::new(),
+            events,
         }
     }
 
     /// Register a PID under a specific name.
     /// If the name already exists, it will be overwritten.
     pub fn register(&self, name: String, pid: Pid) {
-        self.names.insert(name, pid);
+        let event = match self.names.insert(name.clone(), pid) {
+            Some(old) => RegistryEvent::Replaced { name, old, new: pid },
+            None => RegistryEvent::Registered { name, pid },
+        };
+        let _ = self.events.send(event);
     }
 
     /// Retrieve the PID associated with a name.
@@ -31,6 +57,55 @@ impl NameRegistry {
 
     /// Remove a name mapping.
     pub fn unregister(&self, name: &str) {
-        self.names.remove(name);
+        if let Some((name, pid)) = self.names.remove(name) {
+            let _ = self.events.send(RegistryEvent::Unregistered { name, pid });
+        }
+    }
+
+    /// Subscribe to a live stream of `RegistryEvent`s, so callers can react
+    /// to registrations/unregistrations as they happen instead of polling
+    /// `resolve` in a loop.
+    pub fn subscribe(&self) -> impl Stream<Item = RegistryEvent> {
+        let rx = self.events.subscribe();
+        futures::stream::unfold(rx, |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => return Some((event, rx)),
+                    // A lagging subscriber just misses the events it fell
+                    // behind on; there's no queue of record to catch up on.
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    /// Resolve `name` once it's registered, returning immediately if it
+    /// already is. Subscribes before the initial `resolve` check, so a
+    /// `register` racing with this call is never missed.
+    pub async fn await_name(&self, name: &str) -> Pid {
+        let mut rx = self.events.subscribe();
+        if let Some(pid) = self.resolve(name) {
+            return pid;
+        }
+        loop {
+            match rx.recv().await {
+                Ok(RegistryEvent::Registered { name: n, pid }) if n == name => return pid,
+                Ok(RegistryEvent::Replaced { name: n, new, .. }) if n == name => return new,
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(_)) => {
+                    // May have missed the registration while lagged; fall
+                    // back to a direct resolve before continuing to listen.
+                    if let Some(pid) = self.resolve(name) {
+                        return pid;
+                    }
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    // `events` is only dropped along with `self`, so this
+                    // channel can't close while this call is still running.
+                    unreachable!("NameRegistry's event channel closed while awaited");
+                }
+            }
+        }
     }
 }